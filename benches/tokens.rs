@@ -0,0 +1,27 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const DATE_SOURCE: &str = include_str!("./date.lua");
+
+fn tokens_forward(criterion: &mut Criterion) {
+    let ast = full_moon::parse(DATE_SOURCE).unwrap();
+
+    criterion.bench_function("iterate tokens of date", move |b| {
+        b.iter(|| ast.tokens().count())
+    });
+}
+
+fn tokens_backward(criterion: &mut Criterion) {
+    let ast = full_moon::parse(DATE_SOURCE).unwrap();
+
+    criterion.bench_function("iterate tokens of date in reverse", move |b| {
+        b.iter(|| ast.tokens().rev().count())
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = tokens_forward, tokens_backward
+}
+
+criterion_main!(benches);
@@ -1,25 +1,36 @@
 pub mod make_bin_op;
 pub mod parser_structs;
 pub mod punctuated;
+#[cfg(any(feature = "json", feature = "binary"))]
+mod serialization;
 pub mod span;
 
 pub use punctuated::Punctuated;
+#[cfg(any(feature = "json", feature = "binary"))]
+pub use serialization::{SerializationError, SCHEMA_VERSION};
 pub use span::ContainedSpan;
 
 use crate::{
     language::Language,
+    node::{Descendant, Node, TokenItem, Tokens},
     symbols::AnySymbol,
-    tokenizer::{Position, Token, TokenReference, TokenType},
+    tokenizer::{Position, StringLiteralQuoteType, Token, TokenReference, TokenType},
     util::{
         display_option, display_optional_punctuated, display_optional_punctuated_vec,
         join_iterators, join_vec,
     },
 };
 
+#[cfg(feature = "luau")]
+use crate::tokenizer::InterpolatedStringKind;
 use derive_more::Display;
 use full_moon_derive::{Node, Visit};
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, fmt};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+    fmt,
+};
 
 /// An abstract syntax tree, contains all the nodes used in the code
 #[derive(Clone, Debug)]
@@ -61,6 +72,269 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Ast<S, B, U, R>
     pub fn eof(&self) -> &TokenReference<S> {
         &self.eof
     }
+
+    /// Whether another Ast is the same as this one semantically, ignoring positions and trivia
+    /// such as whitespace and comments. The EOF tokens are compared too, but not their trivia.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local x = 1 -- comment")?;
+    /// assert!(ast.similar(&full_moon::parse("local   x=1")?));
+    /// assert!(!ast.similar(&full_moon::parse("local x = 2")?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn similar(&self, other: &Self) -> bool {
+        self.nodes.similar(&other.nodes) && self.eof.similar(&other.eof)
+    }
+
+    /// An iterator over every token reference in the code, in order.
+    /// Unlike [`Node::tokens`], this includes the EOF token at the end.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local x = 1")?;
+    /// assert_eq!(ast.tokens().count(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tokens(&self) -> Tokens<S> {
+        Tokens {
+            items: VecDeque::from([
+                TokenItem::MoreTokens(&self.nodes),
+                TokenItem::TokenReference(&self.eof),
+            ]),
+        }
+    }
+
+    /// The shebang line at the very start of the code, such as `#!/usr/bin/env lua`, if there is one.
+    /// The shebang is kept in the leading trivia of the first token, so printing the Ast reproduces it.
+    /// Use [`Ast::without_shebang`](crate::ast::Ast::without_shebang) to remove it.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("#!/usr/bin/env lua\nprint(1)")?;
+    /// assert_eq!(ast.shebang().unwrap().to_string(), "#!/usr/bin/env lua");
+    /// assert!(full_moon::parse("print(1)")?.shebang().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shebang(&self) -> Option<&Token<S>> {
+        self.tokens()
+            .next()?
+            .leading_trivia()
+            .find(|token| !token.is_bom())
+            .filter(|token| matches!(token.token_type(), TokenType::Shebang { .. }))
+    }
+
+    /// Whether the code started with a UTF-8 byte order mark (BOM).
+    /// The BOM is kept as whitespace in the leading trivia of the first token,
+    /// so printing the Ast reproduces it exactly.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("\u{feff}print(1)")?;
+    /// assert!(ast.had_bom());
+    /// assert_eq!(full_moon::print(&ast), "\u{feff}print(1)");
+    /// assert!(!full_moon::parse("print(1)")?.had_bom());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn had_bom(&self) -> bool {
+        matches!(
+            self.tokens().next().and_then(|token| token.leading_trivia().next()),
+            Some(token) if token.is_bom()
+        )
+    }
+
+    /// An iterator over every comment in the code, both single line and multi line, in order.
+    /// Comments at the end of the code, which are part of the EOF token's trivia, are included.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("-- hello\nlocal x = 1 --[[ world ]]")?;
+    /// let comments: Vec<_> = ast.comments().map(ToString::to_string).collect();
+    /// assert_eq!(comments, vec!["-- hello", "--[[ world ]]"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn comments(&self) -> impl Iterator<Item = &Token<S>> {
+        self.tokens()
+            .flat_map(|token| token.leading_trivia().chain(token.trailing_trivia()))
+            .filter(|token| token.is_comment())
+    }
+
+    /// An iterator over every string literal in the code, in order, such as `"hello"` and
+    /// `[[world]]`. Use [`Token::string_value`] to get the contents of each string with its
+    /// escape sequences decoded, and [`Node::range`] to find where it is.
+    ///
+    /// Luau interpolated strings are only included if they don't have any expressions in them,
+    /// such as `` `hello` ``, as the segments of strings like `` `hello {name}!` `` aren't
+    /// strings on their own.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("print('hello', [[world]], \"\\65\")")?;
+    /// let strings: Vec<_> = ast
+    ///     .string_literals()
+    ///     .filter_map(|token| token.string_value())
+    ///     .collect();
+    /// assert_eq!(strings, vec!["hello", "world", "A"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn string_literals(&self) -> impl Iterator<Item = &TokenReference<S>> {
+        self.tokens().filter(|token| match token.token_type() {
+            TokenType::StringLiteral { .. } => true,
+            #[cfg(feature = "luau")]
+            TokenType::InterpolatedString { kind, .. } => *kind == InterpolatedStringKind::Simple,
+            _ => false,
+        })
+    }
+
+    /// Every statement in the Ast, including those nested inside of other statements.
+    /// Statements are returned in pre-order, meaning each statement comes before the statements
+    /// inside of it, which come before the statement following it.
+    /// The blocks of `if`, `while`, `for`, `repeat`, and `do` statements are included, as well as
+    /// the bodies of every function, such as `local f = function() end`, callbacks passed as
+    /// arguments, and functions inside of a `return`. Last statements, such as `return`, are not
+    /// included themselves.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local x = 1 if x then print(x) end call()")?;
+    /// let statements: Vec<_> = ast
+    ///     .statements()
+    ///     .map(|stmt| stmt.to_string().trim().to_owned())
+    ///     .collect();
+    /// assert_eq!(statements, vec!["local x = 1", "if x then print(x) end", "print(x)", "call()"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn statements(&self) -> impl Iterator<Item = &Stmt<S, B, U, R>> {
+        let mut stack = vec![(self.nodes.stmts(), &self.nodes)];
+
+        std::iter::from_fn(move || loop {
+            let (stmts, block) = stack.last_mut()?;
+
+            let Some(stmt) = stmts.next() else {
+                // Functions in the last statement come after every other statement of the block
+                let block = *block;
+                stack.pop();
+                stack.extend(
+                    block
+                        .last_stmt_blocks()
+                        .into_iter()
+                        .rev()
+                        .map(|block| (block.stmts(), block)),
+                );
+                continue;
+            };
+
+            stack.extend(
+                stmt.nested_blocks()
+                    .into_iter()
+                    .rev()
+                    .map(|block| (block.stmts(), block)),
+            );
+            return Some(stmt);
+        })
+    }
+
+    /// Returns the [`Position`] of the character at the given byte offset of the code.
+    /// If the offset lies in the middle of a multi-byte UTF-8 character, the position of that
+    /// character is returned. Returns `None` if the offset is past the end of the code.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local x = 1\nlocal y = 2")?;
+    /// let position = ast.position_at_byte(18).unwrap();
+    /// assert_eq!((position.line(), position.character()), (2, 7));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn position_at_byte(&self, byte: usize) -> Option<Position> {
+        let eof_position = self.eof.token().start_position();
+        if byte == eof_position.bytes() {
+            return Some(eof_position);
+        }
+
+        for token_reference in self.tokens() {
+            let tokens = token_reference
+                .leading_trivia()
+                .chain(std::iter::once(token_reference.token()))
+                .chain(token_reference.trailing_trivia());
+
+            for token in tokens {
+                if byte < token.start_position().bytes() || byte >= token.end_position().bytes() {
+                    continue;
+                }
+
+                let mut position = token.start_position();
+
+                for character in token.to_string().chars() {
+                    if position.bytes + character.len_utf8() > byte {
+                        return Some(position);
+                    }
+
+                    position.bytes += character.len_utf8();
+
+                    if character == '\n' {
+                        position.line += 1;
+                        position.character = 1;
+                    } else {
+                        position.character += 1;
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The innermost node or token reference whose range contains the given position, such as
+    /// the identifier under a cursor. Positions inside of whitespace or comments between tokens
+    /// return the innermost node surrounding them instead. Ranges are treated as exclusive of
+    /// their end, so a position where one token ends and the next begins, such as the start of
+    /// the `.` in `foo.bar`, returns the next token.
+    /// Returns `None` if the position lies outside of every node, such as in a leading comment.
+    ///
+    /// ```rust
+    /// # use full_moon::node::{Descendant, Node};
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local x = 1")?;
+    /// let position = ast.position_at_byte(6).unwrap();
+    /// let Some(Descendant::TokenReference(token)) = ast.node_at(position) else {
+    ///     panic!("expected a token");
+    /// };
+    /// assert_eq!(token.text(), "x");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn node_at(&self, position: Position) -> Option<Descendant<S>> {
+        let contains = |range: Option<(Position, Position)>| match range {
+            Some((start, end)) => start <= position && position < end,
+            None => false,
+        };
+
+        let mut items = self.tokens().items;
+        let mut innermost = None;
+
+        while let Some(item) = items.into_iter().find(|item| match item {
+            TokenItem::MoreTokens(node) => contains(node.range()),
+            TokenItem::TokenReference(token) => contains(token.range()),
+        }) {
+            match item {
+                TokenItem::MoreTokens(node) => {
+                    innermost = Some(Descendant::Node(node));
+                    items = node.tokens().items;
+                }
+                TokenItem::TokenReference(token) => return Some(Descendant::TokenReference(token)),
+            }
+        }
+
+        innermost
+    }
 }
 
 /// A block of statements, such as in if/do/etc block
@@ -95,6 +369,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Block<S, B, U, R
         self.stmts.iter().map(|(stmt, _)| stmt)
     }
 
+    /// An iterator over the statements in the block, but mutable
+    pub fn stmts_mut(&mut self) -> impl Iterator<Item = &mut Stmt<S, B, U, R>> {
+        self.stmts.iter_mut().map(|(stmt, _)| stmt)
+    }
+
     /// An iterator over the statements in the block, including any optional
     /// semicolon token reference present
     pub fn stmts_with_semicolon(
@@ -108,6 +387,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Block<S, B, U, R
         Some(&self.last_stmt.as_ref()?.0)
     }
 
+    /// The last statement of the block if one exists, but mutable
+    pub fn last_stmt_mut(&mut self) -> Option<&mut LastStmt<S, B, U, R>> {
+        Some(&mut self.last_stmt.as_mut()?.0)
+    }
+
     /// The last statement of the block if on exists, including any optional semicolon token reference present
     pub fn last_stmt_with_semicolon(
         &self,
@@ -115,6 +399,49 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Block<S, B, U, R
         self.last_stmt.as_ref()
     }
 
+    /// The amount of statements in the block, including the last statement if one exists
+    pub fn len(&self) -> usize {
+        self.stmts.len() + usize::from(self.last_stmt.is_some())
+    }
+
+    /// Whether the block has no statements, including the last statement, such as in `do end`
+    pub fn is_empty(&self) -> bool {
+        self.stmts.is_empty() && self.last_stmt.is_none()
+    }
+
+    /// The deepest nesting of blocks inside of the block, where every nested block counts as one level.
+    /// Blocks of `if`, `while`, `for`, `repeat`, and `do` statements are included, as well as the
+    /// bodies of functions, both declared and anonymous, such as callbacks in `call(function() end)`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("if a then while b do end end")?;
+    /// assert_eq!(ast.nodes().max_depth(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn max_depth(&self) -> usize {
+        self.stmts()
+            .flat_map(Stmt::nested_blocks)
+            .chain(self.last_stmt_blocks())
+            .map(|block| block.max_depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // The bodies of functions inside of the last statement, such as `return function() end`
+    fn last_stmt_blocks(&self) -> Vec<&Block<S, B, U, R>> {
+        let mut blocks = Vec::new();
+
+        if let Some(returns) = self.last_stmt().and_then(LastStmt::returns) {
+            for expression in returns.iter() {
+                expression.function_blocks(&mut blocks);
+            }
+        }
+
+        blocks
+    }
+
     /// Returns a new block with the given statements
     /// Takes a vector of statements, followed by an optional semicolon token reference
     pub fn with_stmts(self, stmts: Vec<(Stmt<S, B, U, R>, Option<TokenReference<S>>)>) -> Self {
@@ -130,6 +457,41 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Block<S, B, U, R
         Self { last_stmt, ..self }
     }
 
+    /// Pushes a statement to the end of the block, after every other statement but before the last statement.
+    /// Takes the statement, followed by an optional semicolon token reference
+    pub fn push_stmt(&mut self, stmt: Stmt<S, B, U, R>, semicolon: Option<TokenReference<S>>) {
+        self.stmts.push((stmt, semicolon));
+    }
+
+    /// Inserts a statement at the given index, shifting every statement after it.
+    /// Takes the statement, followed by an optional semicolon token reference.
+    /// The last statement is not counted, and cannot be moved by this.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the amount of statements in the block.
+    pub fn insert_stmt(
+        &mut self,
+        index: usize,
+        stmt: Stmt<S, B, U, R>,
+        semicolon: Option<TokenReference<S>>,
+    ) {
+        self.stmts.insert(index, (stmt, semicolon));
+    }
+
+    /// Removes the statement at the given index, returning it along with its semicolon if one was present.
+    /// The last statement is not counted, use [`Block::with_last_stmt`] to remove it instead.
+    /// Returns None if there is no statement at the index.
+    pub fn remove_stmt(
+        &mut self,
+        index: usize,
+    ) -> Option<(Stmt<S, B, U, R>, Option<TokenReference<S>>)> {
+        if index < self.stmts.len() {
+            Some(self.stmts.remove(index))
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn merge_blocks(&mut self, other: Self) {
         self.stmts.extend(other.stmts);
 
@@ -203,6 +565,112 @@ pub enum Stmt<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     Label(Label),
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Stmt<S, B, U, R> {
+    /// Returns true if the statement declares a new name, such as `local x = 1`,
+    /// `local function x() end`, `function x() end`, and Luau type declarations
+    pub fn is_declaration(&self) -> bool {
+        match self {
+            Stmt::FunctionDeclaration(_) | Stmt::LocalAssignment(_) | Stmt::LocalFunction(_) => {
+                true
+            }
+            #[cfg(feature = "luau")]
+            Stmt::ExportedTypeDeclaration(_) | Stmt::TypeDeclaration(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the statement is a loop, such as `while`, `repeat`, or either kind of `for`
+    pub fn is_loop(&self) -> bool {
+        matches!(
+            self,
+            Stmt::GenericFor(_) | Stmt::NumericFor(_) | Stmt::Repeat(_) | Stmt::While(_)
+        )
+    }
+
+    /// Returns true if the statement branches or jumps, such as `if` or `goto`.
+    /// Loops are not included, use [`Stmt::is_loop`] for those.
+    pub fn is_control_flow(&self) -> bool {
+        match self {
+            Stmt::If(_) => true,
+            #[cfg(feature = "lua52")]
+            Stmt::Goto(_) => true,
+            _ => false,
+        }
+    }
+
+    // The blocks directly inside of the statement, in the order they appear, including the
+    // bodies of functions inside of its expressions.
+    fn nested_blocks(&self) -> Vec<&Block<S, B, U, R>> {
+        let mut blocks = Vec::new();
+
+        match self {
+            Stmt::Assignment(assignment) => {
+                for var in assignment.variables() {
+                    if let Var::Expression(var_expression) = var {
+                        call_function_blocks(
+                            var_expression.prefix(),
+                            var_expression.suffixes(),
+                            &mut blocks,
+                        );
+                    }
+                }
+
+                for expression in assignment.expressions() {
+                    expression.function_blocks(&mut blocks);
+                }
+            }
+            Stmt::Do(do_block) => blocks.push(do_block.block()),
+            Stmt::FunctionCall(call) => {
+                call_function_blocks(call.prefix(), call.suffixes(), &mut blocks)
+            }
+            Stmt::FunctionDeclaration(declaration) => blocks.push(declaration.body().block()),
+            Stmt::GenericFor(generic_for) => {
+                for expression in generic_for.expressions() {
+                    expression.function_blocks(&mut blocks);
+                }
+
+                blocks.push(generic_for.block());
+            }
+            Stmt::If(if_statement) => {
+                for (condition, block) in if_statement.branches() {
+                    if let Some(condition) = condition {
+                        condition.function_blocks(&mut blocks);
+                    }
+
+                    blocks.push(block);
+                }
+            }
+            Stmt::LocalAssignment(local_assignment) => {
+                for expression in local_assignment.expressions() {
+                    expression.function_blocks(&mut blocks);
+                }
+            }
+            Stmt::LocalFunction(local_function) => blocks.push(local_function.body().block()),
+            Stmt::NumericFor(numeric_for) => {
+                numeric_for.start().function_blocks(&mut blocks);
+                numeric_for.end().function_blocks(&mut blocks);
+
+                if let Some(step) = numeric_for.step() {
+                    step.function_blocks(&mut blocks);
+                }
+
+                blocks.push(numeric_for.block());
+            }
+            Stmt::Repeat(repeat) => {
+                blocks.push(repeat.block());
+                repeat.until().function_blocks(&mut blocks);
+            }
+            Stmt::While(while_loop) => {
+                while_loop.condition().function_blocks(&mut blocks);
+                blocks.push(while_loop.block());
+            }
+            _ => {}
+        }
+
+        blocks
+    }
+}
+
 /// The last statement of a [`Block`]
 // #[derive(Clone, Debug, Display, PartialEq, Node, Visit)]
 #[derive(Clone, Debug, Display, PartialEq)]
@@ -219,6 +687,46 @@ pub enum LastStmt<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     Return(R),
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> LastStmt<S, B, U, R> {
+    /// The values being returned, if this is a `return` statement.
+    /// Returns `None` for any other statement, such as `break`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("return 1, 2")?;
+    /// let last_stmt = ast.nodes().last_stmt().unwrap();
+    /// assert_eq!(last_stmt.returns().unwrap().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn returns(&self) -> Option<&Punctuated<Expression<S, B, U, R>, S>> {
+        match self {
+            LastStmt::Return(return_stmt) => Some(return_stmt.returns()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a `break` statement
+    pub fn is_break(&self) -> bool {
+        matches!(self, LastStmt::Break(_))
+    }
+
+    /// Returns true if this is a `return` statement
+    pub fn is_return(&self) -> bool {
+        matches!(self, LastStmt::Return(_))
+    }
+
+    /// Returns true if this is a `continue` statement.
+    /// Always false when the "luau" feature flag is not enabled.
+    pub fn is_continue(&self) -> bool {
+        match self {
+            #[cfg(feature = "luau")]
+            LastStmt::Continue(_) => true,
+            _ => false,
+        }
+    }
+}
+
 /// A `return` statement
 pub trait Return<S: AnySymbol, B: BinOp<S>, U: UnOp<S>> {
     /// The `return` token
@@ -308,6 +816,110 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> TableConstructor
         &self.fields
     }
 
+    /// The value of the field with the given name, such as `1` for `"x"` in `{ x = 1 }`.
+    /// Both `name = value` fields and `["name"] = value` fields with a string literal key are
+    /// matched. If the name is used more than once, the last value is returned, as in Lua.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// # use full_moon::ast::{Expression, Stmt};
+    /// let ast = full_moon::parse("local config = { name = 'moon', ['size'] = 3 }")?;
+    /// let Some(Stmt::LocalAssignment(local)) = ast.nodes().stmts().next() else {
+    ///     unreachable!()
+    /// };
+    /// let Some(Expression::TableConstructor(table)) = local.expressions().iter().next() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(table.get_field("size").and_then(Expression::as_number), Some(3.0));
+    /// assert!(table.get_field("missing").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_field(&self, name: &str) -> Option<&Expression<S, B, U, R>> {
+        self.fields
+            .iter()
+            .filter_map(|field| match field {
+                Field::NameKey { key, value, .. } => match key.token_type() {
+                    TokenType::Identifier { identifier } if identifier.as_str() == name => {
+                        Some(value)
+                    }
+                    _ => None,
+                },
+                Field::ExpressionKey { key, value, .. } => {
+                    (key.as_string().as_deref() == Some(name)).then_some(value)
+                }
+                Field::NoKey(_) => None,
+            })
+            .last()
+    }
+
+    /// The keys that are used more than once, such as both `a`s in `{ a = 1, a = 2 }`.
+    /// Each key is paired with the first key it duplicates, so `{ a = 1, a = 2, a = 3 }`
+    /// returns the first `a` paired with the second, then the first paired with the third.
+    /// Both `name = value` fields and `["name"] = value` fields with a string literal key are
+    /// checked, so `{ a = 1, ["a"] = 2 }` is a duplicate too, as their names are the same once
+    /// escapes are resolved. Any other key, such as `[1]` or `[x]`, is ignored.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// # use full_moon::ast::{Expression, Stmt};
+    /// let ast = full_moon::parse("local t = { a = 1, b = 2, ['a'] = 3 }")?;
+    /// let Some(Stmt::LocalAssignment(local)) = ast.nodes().stmts().next() else {
+    ///     unreachable!()
+    /// };
+    /// let Some(Expression::TableConstructor(table)) = local.expressions().iter().next() else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// let duplicates = table.duplicate_keys();
+    /// assert_eq!(duplicates.len(), 1);
+    /// assert_eq!(duplicates[0].0.token().to_string(), "a");
+    /// assert_eq!(duplicates[0].1.token().to_string(), "'a'");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn duplicate_keys(&self) -> Vec<(&TokenReference<S>, &TokenReference<S>)> {
+        let mut first_keys = HashMap::new();
+        let mut duplicates = Vec::new();
+
+        for field in &self.fields {
+            let (name, token) = match field {
+                Field::NameKey { key, .. } => match key.token_type() {
+                    TokenType::Identifier { identifier } => {
+                        (Cow::Borrowed(identifier.as_str()), key)
+                    }
+                    _ => continue,
+                },
+                Field::ExpressionKey { key, .. } => match key.without_parentheses() {
+                    Expression::String(token) => match token.string_value() {
+                        Some(name) => (name, token),
+                        None => continue,
+                    },
+                    _ => continue,
+                },
+                Field::NoKey(_) => continue,
+            };
+
+            match first_keys.get(&name) {
+                Some(first_key) => duplicates.push((*first_key, token)),
+                None => {
+                    first_keys.insert(name, token);
+                }
+            }
+        }
+
+        duplicates
+    }
+
+    /// The values of the fields without keys, in order, such as `1`, `2`, and `3` in
+    /// `{ 1, 2, x = 4, 3 }`. Fields with keys are skipped, even if their key is a number.
+    pub fn array_values(&self) -> impl Iterator<Item = &Expression<S, B, U, R>> {
+        self.fields.iter().filter_map(|field| match field {
+            Field::NoKey(value) => Some(value),
+            _ => None,
+        })
+    }
+
     /// Returns a new TableConstructor with the given braces
     pub fn with_braces(self, braces: ContainedSpan<S>) -> Self {
         Self { braces, ..self }
@@ -419,6 +1031,198 @@ pub enum Expression<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     Var(Var<S, B, U, R>),
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Expression<S, B, U, R> {
+    /// Creates a number expression with no trivia, such as `3.3`.
+    /// The text is not checked to be a valid number.
+    /// ```rust
+    /// # use full_moon::ast::Expression;
+    /// assert_eq!(Expression::number("0xFF").to_string(), "0xFF");
+    /// ```
+    pub fn number(text: &str) -> Self {
+        Self::Number(TokenReference::number(text))
+    }
+
+    /// Creates a string expression with no trivia, using the given quote type.
+    /// The contents are stored as the literal as is, meaning they must already be escaped
+    /// for the chosen quote type.
+    /// ```rust
+    /// # use full_moon::{ast::Expression, tokenizer::StringLiteralQuoteType};
+    /// let string = Expression::string("hello", StringLiteralQuoteType::Single);
+    /// assert_eq!(string.to_string(), "'hello'");
+    /// ```
+    pub fn string(contents: &str, quote_type: StringLiteralQuoteType) -> Self {
+        Self::String(TokenReference::new(
+            Vec::new(),
+            Token::new(TokenType::StringLiteral {
+                literal: contents.into(),
+                multi_line_depth: 0,
+                quote_type,
+            }),
+            Vec::new(),
+        ))
+    }
+
+    /// The value of a number literal, looking through any parentheses, such as `16` for `(0x10)`.
+    /// Returns `None` if the expression is not a number literal, including negative numbers
+    /// such as `-1`, which are unary operations. See [`Token::parse_number`] for the formats supported.
+    /// ```rust
+    /// # use full_moon::ast::Expression;
+    /// assert_eq!(Expression::number("0x10").as_number(), Some(16.0));
+    /// ```
+    pub fn as_number(&self) -> Option<f64> {
+        match self.without_parentheses() {
+            Expression::Number(token) => token.parse_number(),
+            _ => None,
+        }
+    }
+
+    /// The value of a string literal with its escapes resolved, looking through any parentheses.
+    /// Returns `None` if the expression is not a string literal, or if it has an invalid escape.
+    /// See [`Token::string_value`] for more information.
+    /// ```rust
+    /// # use full_moon::{ast::Expression, tokenizer::StringLiteralQuoteType};
+    /// let string = Expression::string("a\\tb", StringLiteralQuoteType::Double);
+    /// assert_eq!(string.as_string().as_deref(), Some("a\tb"));
+    /// ```
+    pub fn as_string(&self) -> Option<Cow<str>> {
+        match self.without_parentheses() {
+            Expression::String(token) => token.string_value(),
+            _ => None,
+        }
+    }
+
+    /// The value of a `true` or `false` literal, looking through any parentheses.
+    /// Returns `None` for every other expression, including `nil`.
+    pub fn as_bool(&self) -> Option<bool> {
+        let Expression::Symbol(token) = self.without_parentheses() else {
+            return None;
+        };
+
+        match token.token().to_string().as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Whether the expression is always truthy or always falsy, looking through any parentheses.
+    /// In Lua, only `false` and `nil` are falsy, so this is `Some(false)` for them, and `Some(true)`
+    /// for `true`, numbers, strings, tables, and functions, including `0` and `""`.
+    /// Returns `None` for every other expression, such as variables, calls, and operators,
+    /// as their values aren't known until the code is run.
+    ///
+    /// ```rust
+    /// # use full_moon::{ast::Expression, tokenizer::StringLiteralQuoteType};
+    /// assert_eq!(Expression::number("0").const_truthiness(), Some(true));
+    /// assert_eq!(
+    ///     Expression::string("", StringLiteralQuoteType::Double).const_truthiness(),
+    ///     Some(true)
+    /// );
+    /// ```
+    pub fn const_truthiness(&self) -> Option<bool> {
+        match self.without_parentheses() {
+            Expression::Function(_)
+            | Expression::Number(_)
+            | Expression::String(_)
+            | Expression::TableConstructor(_) => Some(true),
+            #[cfg(feature = "luau")]
+            Expression::InterpolatedString(_) => Some(true),
+            Expression::Symbol(token) => match token.token().to_string().as_str() {
+                "true" => Some(true),
+                "false" | "nil" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // Pushes the bodies of every function inside of the expression, in the order they appear.
+    // The bodies themselves aren't searched, as they are blocks of their own.
+    fn function_blocks<'a>(&'a self, blocks: &mut Vec<&'a Block<S, B, U, R>>) {
+        match self {
+            Expression::BinaryOperator { lhs, rhs, .. } => {
+                lhs.function_blocks(blocks);
+                rhs.function_blocks(blocks);
+            }
+            Expression::Parentheses { expression, .. }
+            | Expression::UnaryOperator { expression, .. } => expression.function_blocks(blocks),
+            Expression::Function((_, body)) => blocks.push(body.block()),
+            Expression::FunctionCall(call) => {
+                call_function_blocks(call.prefix(), call.suffixes(), blocks)
+            }
+            Expression::TableConstructor(table) => table_function_blocks(table, blocks),
+            Expression::Var(Var::Expression(var_expression)) => {
+                call_function_blocks(var_expression.prefix(), var_expression.suffixes(), blocks)
+            }
+            // Luau expressions hold the concrete `Expression` of full-moon rather than this one
+            _ => {}
+        }
+    }
+
+    // The expression inside of any amount of parentheses, such as `x` for `((x))`
+    fn without_parentheses(&self) -> &Self {
+        let mut expression = self;
+
+        while let Expression::Parentheses {
+            expression: inner, ..
+        } = expression
+        {
+            expression = inner;
+        }
+
+        expression
+    }
+}
+
+// Pushes the bodies of every function inside of the prefix and suffixes of a call or variable
+fn call_function_blocks<'a, S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>>(
+    prefix: &'a Prefix<S, B, U, R>,
+    suffixes: impl Iterator<Item = &'a Suffix<S, B, U, R>>,
+    blocks: &mut Vec<&'a Block<S, B, U, R>>,
+) {
+    if let Prefix::Expression(expression) = prefix {
+        expression.function_blocks(blocks);
+    }
+
+    for suffix in suffixes {
+        let args = match suffix {
+            Suffix::Index(Index::Brackets { expression, .. }) => {
+                expression.function_blocks(blocks);
+                continue;
+            }
+            Suffix::Index(Index::Dot { .. }) => continue,
+            Suffix::Call(Call::AnonymousCall(args)) => args,
+            Suffix::Call(Call::MethodCall(method_call)) => method_call.args(),
+        };
+
+        match args {
+            FunctionArgs::Parentheses { arguments, .. } => {
+                for argument in arguments.iter() {
+                    argument.function_blocks(blocks);
+                }
+            }
+            FunctionArgs::String(_) => {}
+            FunctionArgs::TableConstructor(table) => table_function_blocks(table, blocks),
+        }
+    }
+}
+
+// Pushes the bodies of every function inside of the keys and values of a table
+fn table_function_blocks<'a, S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>>(
+    table: &'a TableConstructor<S, B, U, R>,
+    blocks: &mut Vec<&'a Block<S, B, U, R>>,
+) {
+    for field in table.fields().iter() {
+        match field {
+            Field::ExpressionKey { key, value, .. } => {
+                key.function_blocks(blocks);
+                value.function_blocks(blocks);
+            }
+            Field::NameKey { value, .. } | Field::NoKey(value) => value.function_blocks(blocks),
+        }
+    }
+}
+
 /// A node used before another in cases such as function calling
 /// The `("foo")` part of `("foo"):upper()`
 // #[derive(Clone, Debug, Display, PartialEq, Node, Visit)]
@@ -434,6 +1238,24 @@ pub enum Prefix<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     Name(TokenReference<S>),
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Prefix<S, B, U, R> {
+    /// The name the prefix starts from, such as `foo` in both `foo.bar` and `(foo).bar`.
+    /// Only one layer of parentheses is looked through, so `((foo)).bar` returns `None`,
+    /// as do prefixes that aren't names at all, such as `("foo"):upper()`.
+    pub fn root_name(&self) -> Option<&TokenReference<S>> {
+        match self {
+            Prefix::Name(name) => Some(name),
+            Prefix::Expression(expression) => match &**expression {
+                Expression::Parentheses { expression, .. } => match &**expression {
+                    Expression::Var(Var::Name(name)) => Some(name),
+                    _ => None,
+                },
+                _ => None,
+            },
+        }
+    }
+}
+
 /// The indexing of something, such as `x.y` or `x["y"]`
 /// Values of variants are the keys, such as `"y"`
 #[derive(Clone, Debug, Display, PartialEq)]
@@ -465,6 +1287,42 @@ pub enum Index<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     },
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Index<S, B, U, R> {
+    /// The key being indexed when it is known without running the code, so that `x.y` and
+    /// `x["y"]` are both `"y"`. String literals have their escapes resolved, and parentheses
+    /// around them are looked through, as with [`Expression::as_string`].
+    /// Returns `None` for any other key, such as `x[y]` or `x[1]`.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::{Expression, Suffix, Var};
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let expression = full_moon::parse_expression("x.y['z'][w]")?;
+    /// let Expression::Var(Var::Expression(var)) = expression else {
+    ///     unreachable!();
+    /// };
+    ///
+    /// let keys: Vec<_> = var
+    ///     .suffixes()
+    ///     .map(|suffix| match suffix {
+    ///         Suffix::Index(index) => index.as_static_key(),
+    ///         _ => unreachable!(),
+    ///     })
+    ///     .collect();
+    /// assert_eq!(keys, vec![Some("y".into()), Some("z".into()), None]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_static_key(&self) -> Option<Cow<str>> {
+        match self {
+            Index::Brackets { expression, .. } => expression.as_string(),
+            Index::Dot { name, .. } => match name.token_type() {
+                TokenType::Identifier { identifier } => Some(Cow::Borrowed(identifier.as_str())),
+                _ => None,
+            },
+        }
+    }
+}
+
 /// Arguments used for a function
 // #[derive(Clone, Debug, Display, PartialEq, Node)]
 #[derive(Clone, Debug, Display, PartialEq)]
@@ -504,6 +1362,43 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> FunctionArgs<S,
             arguments: Punctuated::new(),
         }
     }
+
+    /// The arguments passed in parentheses, such as the `1, 2, 3` in `call(1, 2, 3)`.
+    /// Returns None for calls in the form of `call "foobar"` or `call { 1, 2, 3 }`,
+    /// use [`FunctionArgs::first_argument`] to handle those as well.
+    pub fn arguments(&self) -> Option<&Punctuated<Expression<S, B, U, R>, S>> {
+        match self {
+            FunctionArgs::Parentheses { arguments, .. } => Some(arguments),
+            _ => None,
+        }
+    }
+
+    /// The first argument passed to the function, if there is one.
+    /// Calls in the form of `call "foobar"` and `call { 1, 2, 3 }` are treated as passing
+    /// a single string or table argument. As those forms don't hold an [`Expression`],
+    /// the argument is returned as an owned clone in that case.
+    /// ```rust
+    /// # use full_moon::ast::{Call, Expression, Stmt, Suffix};
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("call \"foobar\"")?;
+    /// let Some(Stmt::FunctionCall(call)) = ast.nodes().stmts().next() else { unreachable!() };
+    /// let Some(Suffix::Call(Call::AnonymousCall(args))) = call.suffixes().next() else { unreachable!() };
+    /// assert!(args.arguments().is_none());
+    /// assert!(matches!(args.first_argument().as_deref(), Some(Expression::String(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn first_argument(&self) -> Option<Cow<'_, Expression<S, B, U, R>>> {
+        match self {
+            FunctionArgs::Parentheses { arguments, .. } => {
+                arguments.iter().next().map(Cow::Borrowed)
+            }
+            FunctionArgs::String(string) => Some(Cow::Owned(Expression::String(string.clone()))),
+            FunctionArgs::TableConstructor(table) => {
+                Some(Cow::Owned(Expression::TableConstructor(table.clone())))
+            }
+        }
+    }
 }
 
 /// A numeric for loop, such as `for index = 1, 10 do end`
@@ -571,6 +1466,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> NumericFor<S, B,
         &self.start
     }
 
+    /// The starting point, but mutable
+    pub fn start_mut(&mut self) -> &mut Expression<S, B, U, R> {
+        &mut self.start
+    }
+
     /// The comma in between the starting point and end point
     /// for _ = 1, 10 do
     ///          ^
@@ -583,6 +1483,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> NumericFor<S, B,
         &self.end
     }
 
+    /// The ending point, but mutable
+    pub fn end_mut(&mut self) -> &mut Expression<S, B, U, R> {
+        &mut self.end
+    }
+
     /// The comma in between the ending point and limit, if one exists
     /// for _ = 0, 10, 2 do
     ///              ^
@@ -595,6 +1500,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> NumericFor<S, B,
         self.step.as_ref()
     }
 
+    /// The step if one exists, but mutable
+    pub fn step_mut(&mut self) -> Option<&mut Expression<S, B, U, R>> {
+        self.step.as_mut()
+    }
+
     /// The `do` token
     pub fn do_token(&self) -> &TokenReference<S> {
         &self.do_token
@@ -605,11 +1515,25 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> NumericFor<S, B,
         &self.block
     }
 
+    /// The code inside the for loop, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `end` token
     pub fn end_token(&self) -> &TokenReference<S> {
         &self.end_token
     }
 
+    /// The range of the code inside the loop, from the end of the `do` token to the start of
+    /// the `end` token. See [`Do::body_range`] for more information.
+    pub fn body_range(&self) -> Option<(Position, Position)> {
+        Some((
+            self.do_token.end_position()?,
+            self.end_token.start_position()?,
+        ))
+    }
+
     /// The type specifiers of the index variable
     /// `for i: number = 1, 10 do` returns:
     /// `Some(TypeSpecifier(number))`
@@ -797,6 +1721,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> GenericFor<S, B,
         &self.expr_list
     }
 
+    /// The expressions looped over, but mutable
+    pub fn expressions_mut(&mut self) -> &mut Punctuated<Expression<S, B, U, R>, S> {
+        &mut self.expr_list
+    }
+
     /// The `do` token
     pub fn do_token(&self) -> &TokenReference<S> {
         &self.do_token
@@ -807,11 +1736,25 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> GenericFor<S, B,
         &self.block
     }
 
+    /// The code inside the for loop, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `end` token
     pub fn end_token(&self) -> &TokenReference<S> {
         &self.end_token
     }
 
+    /// The range of the code inside the loop, from the end of the `do` token to the start of
+    /// the `end` token. See [`Do::body_range`] for more information.
+    pub fn body_range(&self) -> Option<(Position, Position)> {
+        Some((
+            self.do_token.end_position()?,
+            self.end_token.start_position()?,
+        ))
+    }
+
     /// The type specifiers of the named variables, in the order that they were assigned.
     /// `for i, v: string in pairs() do` returns an iterator containing:
     /// `None, Some(TypeSpecifier(string))`
@@ -953,6 +1896,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> If<S, B, U, R> {
         &self.condition
     }
 
+    /// The condition of the if statement, but mutable
+    pub fn condition_mut(&mut self) -> &mut Expression<S, B, U, R> {
+        &mut self.condition
+    }
+
     /// The `then` token
     pub fn then_token(&self) -> &TokenReference<S> {
         &self.then_token
@@ -963,6 +1911,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> If<S, B, U, R> {
         &self.block
     }
 
+    /// The block inside the initial if statement, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `else` token if one exists
     pub fn else_token(&self) -> Option<&TokenReference<S>> {
         self.else_token.as_ref()
@@ -975,16 +1928,74 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> If<S, B, U, R> {
         self.else_if.as_ref()
     }
 
+    /// The `elseif` conditions if there are any, but mutable
+    pub fn else_if_mut(&mut self) -> Option<&mut Vec<ElseIf<S, B, U, R>>> {
+        self.else_if.as_mut()
+    }
+
     /// The code inside an `else` block if one exists
     pub fn else_block(&self) -> Option<&Block<S, B, U, R>> {
         self.r#else.as_ref()
     }
 
+    /// The code inside an `else` block if one exists, but mutable
+    pub fn else_block_mut(&mut self) -> Option<&mut Block<S, B, U, R>> {
+        self.r#else.as_mut()
+    }
+
     /// The `end` token
     pub fn end_token(&self) -> &TokenReference<S> {
         &self.end_token
     }
 
+    /// The range of the code inside each branch, in the same order as [`If::branches`].
+    /// Each range goes from the end of the `then` or `else` token that starts the branch, to the
+    /// start of the `elseif`, `else`, or `end` token that ends it.
+    /// See [`Do::body_range`] for more information.
+    pub fn body_ranges(&self) -> Option<Vec<(Position, Position)>> {
+        let else_ifs = self.else_if.iter().flatten();
+
+        let starts = std::iter::once(&self.then_token)
+            .chain(else_ifs.clone().map(|else_if| &else_if.then_token))
+            .chain(self.else_token.iter());
+        let ends = else_ifs
+            .map(|else_if| &else_if.else_if_token)
+            .chain(self.else_token.iter())
+            .chain(std::iter::once(&self.end_token));
+
+        starts
+            .zip(ends)
+            .map(|(start, end)| Some((start.end_position()?, end.start_position()?)))
+            .collect()
+    }
+
+    /// Every branch of the if statement in order, as the condition and the code run if it is true.
+    /// This is the `if` branch first, then every `elseif` branch, and finally the `else` branch,
+    /// which has no condition, if one exists.
+    ///
+    /// ```rust
+    /// # use full_moon::ast;
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("if a then elseif b then else end")?;
+    /// let Some(ast::Stmt::If(if_stmt)) = ast.nodes().stmts().next() else { unreachable!() };
+    /// assert_eq!(if_stmt.branches().count(), 3);
+    /// assert!(if_stmt.branches().last().unwrap().0.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn branches(
+        &self,
+    ) -> impl Iterator<Item = (Option<&Expression<S, B, U, R>>, &Block<S, B, U, R>)> {
+        std::iter::once((Some(&self.condition), &self.block))
+            .chain(
+                self.else_if
+                    .iter()
+                    .flatten()
+                    .map(|else_if| (Some(else_if.condition()), else_if.block())),
+            )
+            .chain(self.r#else.iter().map(|block| (None, block)))
+    }
+
     /// Returns a new If with the given `if` token
     pub fn with_if_token(self, if_token: TokenReference<S>) -> Self {
         Self { if_token, ..self }
@@ -1059,6 +2070,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> ElseIf<S, B, U,
         &self.condition
     }
 
+    /// The condition of the `elseif`, but mutable
+    pub fn condition_mut(&mut self) -> &mut Expression<S, B, U, R> {
+        &mut self.condition
+    }
+
     /// The `then` token
     pub fn then_token(&self) -> &TokenReference<S> {
         &self.then_token
@@ -1069,6 +2085,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> ElseIf<S, B, U,
         &self.block
     }
 
+    /// The body of the `elseif`, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// Returns a new ElseIf with the given `elseif` token
     pub fn with_else_if_token(self, else_if_token: TokenReference<S>) -> Self {
         Self {
@@ -1128,6 +2149,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> While<S, B, U, R
         &self.condition
     }
 
+    /// The condition of the while loop, but mutable
+    pub fn condition_mut(&mut self) -> &mut Expression<S, B, U, R> {
+        &mut self.condition
+    }
+
     /// The `do` token
     pub fn do_token(&self) -> &TokenReference<S> {
         &self.do_token
@@ -1138,11 +2164,25 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> While<S, B, U, R
         &self.block
     }
 
+    /// The code inside the while loop, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `end` token
     pub fn end_token(&self) -> &TokenReference<S> {
         &self.end_token
     }
 
+    /// The range of the code inside the loop, from the end of the `do` token to the start of
+    /// the `end` token. See [`Do::body_range`] for more information.
+    pub fn body_range(&self) -> Option<(Position, Position)> {
+        Some((
+            self.do_token.end_position()?,
+            self.end_token.start_position()?,
+        ))
+    }
+
     /// Returns a new While with the given `while` token
     pub fn with_while_token(self, while_token: TokenReference<S>) -> Self {
         Self {
@@ -1205,16 +2245,35 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Repeat<S, B, U,
         &self.block
     }
 
+    /// The code inside the `repeat` block, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `until` token
     pub fn until_token(&self) -> &TokenReference<S> {
         &self.until_token
     }
 
+    /// The range of the code inside the loop, from the end of the `repeat` token to the start of
+    /// the `until` token. See [`Do::body_range`] for more information.
+    pub fn body_range(&self) -> Option<(Position, Position)> {
+        Some((
+            self.repeat_token.end_position()?,
+            self.until_token.start_position()?,
+        ))
+    }
+
     /// The condition for the `until` part
     pub fn until(&self) -> &Expression<S, B, U, R> {
         &self.until
     }
 
+    /// The condition for the `until` part, but mutable
+    pub fn until_mut(&mut self) -> &mut Expression<S, B, U, R> {
+        &mut self.until
+    }
+
     /// Returns a new Repeat with the given `repeat` token
     pub fn with_repeat_token(self, repeat_token: TokenReference<S>) -> Self {
         Self {
@@ -1242,6 +2301,59 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Repeat<S, B, U,
     }
 }
 
+/// A statement or branch that depends on a condition: [`If`], [`ElseIf`], [`While`], and [`Repeat`].
+/// Lets code such as lints handle every kind of condition the same way.
+/// The condition of a [`Repeat`] is its `until` expression, which stops the loop when true,
+/// rather than letting it run.
+///
+/// ```rust
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// # use full_moon::ast::{HasCondition, Stmt};
+/// let ast = full_moon::parse("repeat until done")?;
+/// let Some(Stmt::Repeat(repeat)) = ast.nodes().stmts().next() else {
+///     unreachable!()
+/// };
+/// assert_eq!(repeat.condition().to_string(), "done");
+/// # Ok(())
+/// # }
+/// ```
+pub trait HasCondition<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
+    /// The condition, such as `x` in `if x then end`, or `y` in `repeat until y`
+    fn condition(&self) -> &Expression<S, B, U, R>;
+}
+
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> HasCondition<S, B, U, R>
+    for If<S, B, U, R>
+{
+    fn condition(&self) -> &Expression<S, B, U, R> {
+        &self.condition
+    }
+}
+
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> HasCondition<S, B, U, R>
+    for ElseIf<S, B, U, R>
+{
+    fn condition(&self) -> &Expression<S, B, U, R> {
+        &self.condition
+    }
+}
+
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> HasCondition<S, B, U, R>
+    for While<S, B, U, R>
+{
+    fn condition(&self) -> &Expression<S, B, U, R> {
+        &self.condition
+    }
+}
+
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> HasCondition<S, B, U, R>
+    for Repeat<S, B, U, R>
+{
+    fn condition(&self) -> &Expression<S, B, U, R> {
+        &self.until
+    }
+}
+
 /// A method call, such as `x:y()`
 // #[derive(Clone, Debug, Display, PartialEq, Node, Visit)]
 #[derive(Clone, Debug, Display, PartialEq)]
@@ -1278,6 +2390,16 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> MethodCall<S, B,
         &self.name
     }
 
+    /// The text of the method being called, `"call"` in `method:call()`.
+    /// Returns `None` if the name is not an identifier, which can only happen if it was replaced
+    /// with [`MethodCall::with_name`].
+    pub fn method_name(&self) -> Option<&str> {
+        match self.name.token_type() {
+            TokenType::Identifier { identifier } => Some(identifier.as_str()),
+            _ => None,
+        }
+    }
+
     /// Returns a new MethodCall with the given `:` token
     pub fn with_colon_token(self, colon_token: TokenReference<S>) -> Self {
         Self {
@@ -1311,6 +2433,14 @@ pub enum Call<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     MethodCall(MethodCall<S, B, U, R>),
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Call<S, B, U, R> {
+    /// Returns true if the call is a method call, such as `:y()` in `x:y()`, which passes what
+    /// it's called on as `self`. Returns false for direct calls, such as `()` in `x.y()`
+    pub fn is_method_call(&self) -> bool {
+        matches!(self, Call::MethodCall(_))
+    }
+}
+
 /// A function body, everything except `function x` in `function x(a, b, c) call() end`
 // #[derive(Clone, Debug, PartialEq, Node)]
 #[derive(Clone, Debug, PartialEq)]
@@ -1372,11 +2502,34 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> FunctionBody<S,
         &self.block
     }
 
+    /// The code of a function body, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `end` token
     pub fn end_token(&self) -> &TokenReference<S> {
         &self.end_token
     }
 
+    /// The range of the code inside the function, from the end of the parameters to the start
+    /// of the `end` token. In Luau, the range starts after the return type, if there is one.
+    /// See [`Do::body_range`] for more information.
+    pub fn body_range(&self) -> Option<(Position, Position)> {
+        #[cfg(feature = "luau")]
+        if let Some(return_type) = &self.return_type {
+            return Some((
+                return_type.end_position()?,
+                self.end_token.start_position()?,
+            ));
+        }
+
+        Some((
+            self.parameters_parentheses.tokens().1.end_position()?,
+            self.end_token.start_position()?,
+        ))
+    }
+
     /// The generics declared for the function body.
     /// The `<T, U>` part of `function x<T, U>() end`
     /// Only available when the "luau" feature flag is enabled.
@@ -1401,6 +2554,28 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> FunctionBody<S,
         self.return_type.as_ref()
     }
 
+    /// The amount of named parameters the function takes, not including `...`.
+    /// `function(a, b, ...)` has a parameter count of 2.
+    pub fn parameter_count(&self) -> usize {
+        self.named_parameters().count()
+    }
+
+    /// Returns true if the function takes a variable amount of arguments, such as `function(a, ...)`
+    pub fn is_variadic(&self) -> bool {
+        matches!(self.parameters.last(), Some(pair) if pair.value().is_vararg())
+    }
+
+    /// The names of the parameters, skipping over `...`.
+    /// `function(a, b, ...)` returns an iterator containing `a` and `b`.
+    pub fn named_parameters(&self) -> impl Iterator<Item = &TokenReference<S>> {
+        self.parameters
+            .iter()
+            .filter_map(|parameter| match parameter {
+                Parameter::Name(name) => Some(name),
+                Parameter::Ellipse(_) => None,
+            })
+    }
+
     /// Returns a new FunctionBody with the given parentheses for the parameters
     pub fn with_parameters_parentheses(self, parameters_parentheses: ContainedSpan<S>) -> Self {
         Self {
@@ -1501,13 +2676,27 @@ pub enum Parameter<S: AnySymbol> {
     Name(TokenReference<S>),
 }
 
+impl<S: AnySymbol> Parameter<S> {
+    /// The token of the parameter, either the name or the `...`
+    pub fn token(&self) -> &TokenReference<S> {
+        match self {
+            Parameter::Ellipse(token) | Parameter::Name(token) => token,
+        }
+    }
+
+    /// Whether the parameter is the `...` vararg syntax
+    pub fn is_vararg(&self) -> bool {
+        matches!(self, Parameter::Ellipse(_))
+    }
+}
+
 /// A suffix in certain cases, such as `:y()` in `x:y()`
 /// Can be stacked on top of each other, such as in `x()()()`
 // #[derive(Clone, Debug, Display, PartialEq, Node, Visit)]
 #[derive(Clone, Debug, Display, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[non_exhaustive]
-pub enum Suffix<S: AnySymbol, B: BinOp<S>, U: BinOp<S>, R: Return<S, B, U>> {
+pub enum Suffix<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> {
     #[display(fmt = "{_0}")]
     /// A call, including method calls and direct calls
     Call(Call<S, B, U, R>),
@@ -1516,6 +2705,34 @@ pub enum Suffix<S: AnySymbol, B: BinOp<S>, U: BinOp<S>, R: Return<S, B, U>> {
     Index(Index<S, B, U, R>),
 }
 
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Suffix<S, B, U, R> {
+    /// The method call of the suffix, if it is one, such as `:y()` in `x:y()`.
+    /// Returns `None` for direct calls, such as `()` in `x.y()`, and for indexes.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::{Expression, Suffix, Var};
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let expression = full_moon::parse_expression("x:y().z")?;
+    /// let Expression::Var(Var::Expression(var)) = expression else {
+    ///     unreachable!();
+    /// };
+    ///
+    /// let method_names: Vec<_> = var
+    ///     .suffixes()
+    ///     .map(|suffix| suffix.as_method_call().and_then(|call| call.method_name()))
+    ///     .collect();
+    /// assert_eq!(method_names, vec![Some("y"), None]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_method_call(&self) -> Option<&MethodCall<S, B, U, R>> {
+        match self {
+            Suffix::Call(Call::MethodCall(method_call)) => Some(method_call),
+            _ => None,
+        }
+    }
+}
+
 /// A complex expression used by [`Var`], consisting of both a prefix and suffixes
 // #[derive(Clone, Debug, Display, PartialEq, Node, Visit)]
 #[derive(Clone, Debug, Display, PartialEq)]
@@ -1545,6 +2762,20 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> VarExpression<S,
         self.suffixes.iter()
     }
 
+    /// The name the expression starts with, such as `x` in `x.y.z`.
+    /// Returns `None` if the prefix is a parenthesized expression, such as in `("foo"):upper()`.
+    pub fn base_name(&self) -> Option<&TokenReference<S>> {
+        match &self.prefix {
+            Prefix::Name(name) => Some(name),
+            Prefix::Expression(_) => None,
+        }
+    }
+
+    /// Whether the expression has no suffixes, meaning it is neither indexed nor called
+    pub fn is_simple(&self) -> bool {
+        self.suffixes.is_empty()
+    }
+
     /// Returns a new VarExpression with the given prefix
     pub fn with_prefix(self, prefix: Prefix<S, B, U, R>) -> Self {
         Self { prefix, ..self }
@@ -1600,6 +2831,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Assignment<S, B,
         &self.expr_list
     }
 
+    /// The expressions being assigned, but mutable
+    pub fn expressions_mut(&mut self) -> &mut Punctuated<Expression<S, B, U, R>, S> {
+        &mut self.expr_list
+    }
+
     /// The `=` token in between `x = y`
     pub fn equal_token(&self) -> &TokenReference<S> {
         &self.equal_token
@@ -1611,6 +2847,19 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Assignment<S, B,
         &self.var_list
     }
 
+    /// Pairs every variable with the expression assigned to it, in order.
+    /// `x, y = 1` yields `(x, Some(1))` and `(y, None)`, while extra expressions such as the
+    /// `2` in `x = 1, 2` are skipped. Like in Lua, only the position is used, so variables after
+    /// a function call, such as `y` in `x, y = f()`, have no expression of their own.
+    pub fn bindings(
+        &self,
+    ) -> impl Iterator<Item = (&Var<S, B, U, R>, Option<&Expression<S, B, U, R>>)> {
+        let mut expressions = self.expr_list.iter();
+        self.var_list
+            .iter()
+            .map(move |variable| (variable, expressions.next()))
+    }
+
     /// Returns a new Assignment with the given variables
     pub fn with_variables(self, var_list: Punctuated<Var<S, B, U, R>, S>) -> Self {
         Self { var_list, ..self }
@@ -1675,6 +2924,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> LocalFunction<S,
         &self.body
     }
 
+    /// The function body, but mutable
+    pub fn body_mut(&mut self) -> &mut FunctionBody<S, B, U, R> {
+        &mut self.body
+    }
+
     /// The name of the function, the `x` part of `local function x() end`
     pub fn name(&self) -> &TokenReference<S> {
         &self.name
@@ -1761,12 +3015,49 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> LocalAssignment<
         &self.expr_list
     }
 
+    /// The expressions being assigned, but mutable
+    pub fn expressions_mut(&mut self) -> &mut Punctuated<Expression<S, B, U, R>, S> {
+        &mut self.expr_list
+    }
+
     /// Returns the punctuated sequence of names being assigned to.
     /// This is the `x, y` part of `local x, y = 1, 2`
     pub fn names(&self) -> &Punctuated<TokenReference<S>, S> {
         &self.name_list
     }
 
+    /// Pairs every name with the expression it is initialized to, in order.
+    /// `local x, y = 1` yields `(x, Some(1))` and `(y, None)`, while extra expressions such as the
+    /// `2` in `local x = 1, 2` are skipped. Like in Lua, only the position is used, so names after
+    /// a function call, such as `y` in `local x, y = f()`, have no expression of their own.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::Stmt;
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local a, b = 1")?;
+    /// let Some(Stmt::LocalAssignment(local)) = ast.nodes().stmts().next() else {
+    ///     unreachable!()
+    /// };
+    /// let bindings = local
+    ///     .bindings()
+    ///     .map(|(name, value)| (name.token().to_string(), value.map(ToString::to_string)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     bindings,
+    ///     vec![("a".to_owned(), Some("1".to_owned())), ("b".to_owned(), None)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bindings(
+        &self,
+    ) -> impl Iterator<Item = (&TokenReference<S>, Option<&Expression<S, B, U, R>>)> {
+        let mut expressions = self.expr_list.iter();
+        self.name_list
+            .iter()
+            .map(move |name| (name, expressions.next()))
+    }
+
     /// The type specifiers of the variables, in the order that they were assigned.
     /// `local foo: number, bar, baz: boolean` returns an iterator containing:
     /// `Some(TypeSpecifier(number)), None, Some(TypeSpecifier(boolean))`
@@ -1776,6 +3067,23 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> LocalAssignment<
         self.type_specifiers.iter().map(Option::as_ref)
     }
 
+    /// Pairs every name with its type, in order.
+    /// `local foo: number, bar` returns an iterator containing:
+    /// `(foo, Some(TypeInfo(number))), (bar, None)`
+    /// Only available when the "luau" feature flag is enabled.
+    #[cfg(feature = "luau")]
+    pub fn typed_bindings(&self) -> impl Iterator<Item = (&TokenReference<S>, Option<&TypeInfo>)> {
+        self.name_list.iter().enumerate().map(|(index, name)| {
+            let type_info = self
+                .type_specifiers
+                .get(index)
+                .and_then(Option::as_ref)
+                .map(TypeSpecifier::type_info);
+
+            (name, type_info)
+        })
+    }
+
     /// The attributes specified for the variables, in the order that they were assigned.
     /// `local foo <const>, bar, baz <close>` returns an iterator containing:
     /// `Some(Attribute("const")), None, Some(Attribute("close"))`
@@ -1883,11 +3191,42 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Do<S, B, U, R> {
         &self.block
     }
 
+    /// The code inside the `do ... end`, but mutable
+    pub fn block_mut(&mut self) -> &mut Block<S, B, U, R> {
+        &mut self.block
+    }
+
     /// The `end` token
     pub fn end_token(&self) -> &TokenReference<S> {
         &self.end_token
     }
 
+    /// The range of the code inside the `do ... end`, from the end of the `do` token to the start
+    /// of the `end` token, such as for folding it in an editor. The keywords themselves are not
+    /// included, but the trivia around them is.
+    /// Returns `None` if either token doesn't have a position.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::Stmt;
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let code = "do local x = 1 end";
+    /// let ast = full_moon::parse(code)?;
+    /// let Some(Stmt::Do(do_block)) = ast.nodes().stmts().next() else {
+    ///     unreachable!();
+    /// };
+    ///
+    /// let (start, end) = do_block.body_range().unwrap();
+    /// assert_eq!(&code[start.bytes()..end.bytes()], " local x = 1 ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_range(&self) -> Option<(Position, Position)> {
+        Some((
+            self.do_token.end_position()?,
+            self.end_token.start_position()?,
+        ))
+    }
+
     /// Returns a new Do with the given `do` token
     pub fn with_do_token(self, do_token: TokenReference<S>) -> Self {
         Self { do_token, ..self }
@@ -1948,6 +3287,41 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> FunctionCall<S,
         self.suffixes.iter()
     }
 
+    /// The name of the function being called as a dotted path, such as `table.insert` for
+    /// `table.insert(list, 1)`, or `object:method` for `object:method()`.
+    /// Returns None when the function being called isn't a simple chain of names, such as in
+    /// `(call)()`, `list[1]()`, or `call()()`.
+    pub fn called_name(&self) -> Option<String> {
+        let Prefix::Name(name) = &self.prefix else {
+            return None;
+        };
+
+        let (last, indexes) = self.suffixes.split_last()?;
+        let mut called_name = name.token().to_string();
+
+        for suffix in indexes {
+            match suffix {
+                Suffix::Index(Index::Dot { name, .. }) => {
+                    called_name.push('.');
+                    called_name.push_str(&name.token().to_string());
+                }
+
+                _ => return None,
+            }
+        }
+
+        match last {
+            Suffix::Call(Call::AnonymousCall(_)) => {}
+            Suffix::Call(Call::MethodCall(method_call)) => {
+                called_name.push(':');
+                called_name.push_str(&method_call.name().token().to_string());
+            }
+            Suffix::Index(_) => return None,
+        }
+
+        Some(called_name)
+    }
+
     /// Returns a new FunctionCall with the given prefix
     pub fn with_prefix(self, prefix: Prefix<S, B, U, R>) -> Self {
         Self { prefix, ..self }
@@ -2012,6 +3386,75 @@ impl<S: AnySymbol> FunctionName<S> {
             ..self
         }
     }
+
+    /// The names and method name as a single string without any trivia, such as `"a.b.c:d"`
+    /// for `function a . b.c : d() end`. The opposite of [`FunctionName::from_path`].
+    pub fn to_path(&self) -> String {
+        let mut path = self
+            .names
+            .iter()
+            .map(|name| name.token().to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        if let Some(method_name) = self.method_name() {
+            path.push(':');
+            path.push_str(&method_name.token().to_string());
+        }
+
+        path
+    }
+
+    /// Creates a new FunctionName from a string of names separated by dots, optionally followed
+    /// by a colon and a method name, such as `"a.b.c:d"`. The opposite of [`FunctionName::to_path`].
+    /// Returns `None` if the path is malformed, such as when a name is empty, a keyword, or not
+    /// a valid identifier, or when there is more than one colon.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::FunctionName;
+    /// let name = FunctionName::from_path("a.b.c:d").unwrap();
+    /// assert_eq!(name.to_string(), "a.b.c:d");
+    /// assert_eq!(name.method_name().unwrap().to_string(), "d");
+    ///
+    /// assert!(FunctionName::from_path("a..b").is_none());
+    /// assert!(FunctionName::from_path("a:b:c").is_none());
+    /// ```
+    pub fn from_path(path: &str) -> Option<Self> {
+        // Keywords, such as `end` or `goto` in Lua 5.2, are read as symbols rather than names
+        let is_name = |name: &str| {
+            let mut characters = name.chars();
+            matches!(characters.next(), Some(first) if crate::lexer::is_identifier_start(first))
+                && characters.all(crate::lexer::is_identifier_continue)
+                && name.parse::<S>().is_err()
+        };
+
+        // A second colon is kept in the method name, which then isn't a valid name
+        let (names, method_name) = match path.split_once(':') {
+            Some((names, method_name)) => (names, Some(method_name)),
+            None => (path, None),
+        };
+
+        if !names.split('.').all(is_name) {
+            return None;
+        }
+
+        let method = match method_name {
+            Some(method_name) if is_name(method_name) => Some((
+                TokenReference::basic_symbol(":"),
+                TokenReference::identifier(method_name),
+            )),
+            Some(_) => return None,
+            None => None,
+        };
+
+        Some(
+            Self::new(Punctuated::from_values(
+                names.split('.').map(TokenReference::identifier),
+                TokenReference::basic_symbol("."),
+            ))
+            .with_method(method),
+        )
+    }
 }
 
 /// A normal function declaration, supports simple declarations like `function x() end`
@@ -2047,6 +3490,11 @@ impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> FunctionDeclarat
         &self.body
     }
 
+    /// The body of the function, but mutable
+    pub fn body_mut(&mut self) -> &mut FunctionBody<S, B, U, R> {
+        &mut self.body
+    }
+
     /// The name of the function
     pub fn name(&self) -> &FunctionName<S> {
         &self.name
@@ -2101,6 +3549,10 @@ pub struct AstError<S: AnySymbol> {
     /// If set, this is the complete range of the error
     #[serde(skip_serializing_if = "Option::is_none")]
     range: Option<(Position, Position)>,
+
+    /// Whether the error was caused by a missing token, which is usually replaced by a phantom token
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    missing_token: bool,
 }
 
 impl<S: AnySymbol> AstError<S> {
@@ -2115,6 +3567,21 @@ impl<S: AnySymbol> AstError<S> {
             .or_else(|| Some((self.token.start_position(), self.token.end_position())))
             .unwrap()
     }
+
+    /// Whether the error was caused by a specific token the parser expected but didn't find,
+    /// such as the `)` in `call(1, 2`. When parsing with [`parse_fallible`](crate::parse_fallible),
+    /// these are usually recovered from by inserting a phantom token in its place.
+    pub fn is_missing_token(&self) -> bool {
+        self.missing_token
+    }
+
+    /// Returns a new AstError with the given range
+    pub fn with_range(self, range: (Position, Position)) -> Self {
+        Self {
+            range: Some(range),
+            ..self
+        }
+    }
 }
 
 impl<S: AnySymbol> fmt::Display for AstError<S> {
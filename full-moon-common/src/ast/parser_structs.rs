@@ -4,12 +4,15 @@ use crate::{
     language::Language,
     lexer::{Lexer, LexerResult},
     symbols::AnySymbol,
-    tokenizer::TokenReference,
+    tokenizer::{Position, TokenReference},
 };
 
 pub struct ParserState<S: AnySymbol, L: Language<S>> {
     errors: Vec<crate::Error<S>>,
     lexer: L::Lex,
+    lua_version: L::Version,
+    // Whether a required token was missing, and so a phantom token was put in its place
+    synthesized_tokens: bool,
 }
 
 impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
@@ -17,9 +20,26 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
         Self {
             errors: Vec::new(),
             lexer,
+            lua_version: L::Version::default(),
+            synthesized_tokens: false,
         }
     }
 
+    pub fn with_lua_version(self, lua_version: L::Version) -> Self {
+        Self {
+            lua_version,
+            ..self
+        }
+    }
+
+    pub fn lua_version(&self) -> L::Version {
+        self.lua_version
+    }
+
+    pub fn synthesized_tokens(&self) -> bool {
+        self.synthesized_tokens
+    }
+
     pub fn current(&self) -> Result<&TokenReference<S>, ()> {
         match self.lexer.current() {
             Some(LexerResult::Ok(token) | LexerResult::Recovered(token, _)) => Ok(token),
@@ -82,7 +102,7 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
                 if token.is_symbol(symbol) {
                     Some(self.consume().unwrap())
                 } else {
-                    self.token_error(token.clone(), error);
+                    self.missing_token_error(token.clone(), error);
                     None
                 }
             }
@@ -102,7 +122,7 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
                 if token.is_symbol(symbol) {
                     Some(self.consume().unwrap())
                 } else {
-                    self.token_error(reference_token.clone(), error);
+                    self.missing_token_error(reference_token.clone(), error);
                     None
                 }
             }
@@ -123,7 +143,12 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
                 if token.is_symbol(symbol) {
                     Some(self.consume().unwrap())
                 } else {
-                    self.token_error_ranged(token.clone(), error.to_str(), start_token, end_token);
+                    self.missing_token_error_ranged(
+                        token.clone(),
+                        error.to_str(),
+                        start_token,
+                        end_token,
+                    );
                     None
                 }
             }
@@ -145,7 +170,7 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
                 } else {
                     let (start_token, end_token) = tokens();
 
-                    self.token_error_ranged(
+                    self.missing_token_error_ranged(
                         token.clone(),
                         error.to_str(),
                         &start_token,
@@ -165,12 +190,7 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
         token_reference: TokenReference<S>,
         error: E,
     ) {
-        self.errors
-            .push(crate::Error::AstError(crate::ast::AstError {
-                token: token_reference.token,
-                additional: error.into(),
-                range: None,
-            }));
+        self.push_ast_error(token_reference, error.into(), None, false);
     }
 
     // This takes start_token and end_token as owned references because otherwise, we tend to stack an immutable over mutable borrow.
@@ -181,11 +201,53 @@ impl<S: AnySymbol, L: Language<S>> ParserState<S, L> {
         start_token: &TokenReference<S>,
         end_token: &TokenReference<S>,
     ) {
+        self.push_ast_error(
+            token_reference,
+            error.into(),
+            Some((start_token.start_position(), end_token.end_position())),
+            false,
+        );
+    }
+
+    // Used when a required token is missing, which callers replace with a phantom token
+    fn missing_token_error<E: Into<Cow<'static, str>>>(
+        &mut self,
+        token_reference: TokenReference<S>,
+        error: E,
+    ) {
+        self.push_ast_error(token_reference, error.into(), None, true);
+    }
+
+    fn missing_token_error_ranged<E: Into<Cow<'static, str>>>(
+        &mut self,
+        token_reference: TokenReference<S>,
+        error: E,
+        start_token: &TokenReference<S>,
+        end_token: &TokenReference<S>,
+    ) {
+        self.push_ast_error(
+            token_reference,
+            error.into(),
+            Some((start_token.start_position(), end_token.end_position())),
+            true,
+        );
+    }
+
+    fn push_ast_error(
+        &mut self,
+        token_reference: TokenReference<S>,
+        additional: Cow<'static, str>,
+        range: Option<(Position, Position)>,
+        missing_token: bool,
+    ) {
+        self.synthesized_tokens |= missing_token;
+
         self.errors
             .push(crate::Error::AstError(crate::ast::AstError {
                 token: token_reference.token,
-                additional: error.into(),
-                range: Some((start_token.start_position(), end_token.end_position())),
+                additional,
+                range,
+                missing_token,
             }));
     }
 }
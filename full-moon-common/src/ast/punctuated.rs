@@ -19,12 +19,12 @@ use crate::{
     symbols::AnySymbol,
     tokenizer::{Position, TokenReference},
     util,
-    visitors::{Visit, VisitMut},
+    visitors::{TryVisit, Visit, VisitMut},
 };
 use derive_more::Display;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, iter::FromIterator};
+use std::{fmt::Display, iter::FromIterator, ops::ControlFlow};
 
 /// A punctuated sequence of node `T` separated by
 /// [`TokenReference`](crate::tokenizer::TokenReference).
@@ -47,6 +47,22 @@ impl<T, S: AnySymbol> Punctuated<T, S> {
         Self { pairs: Vec::new() }
     }
 
+    /// Creates a punctuated sequence from the given values, placing a clone of `separator`
+    /// between each of them. The last value will have no punctuation.
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// # use full_moon::tokenizer::TokenReference;
+    /// let comma = TokenReference::symbol(", ").unwrap();
+    /// let mut punctuated = Punctuated::from_values(vec![1, 2], comma.clone());
+    /// assert_eq!(punctuated.pop(), Some(Pair::new(2, None)));
+    /// assert_eq!(punctuated.pop(), Some(Pair::new(1, Some(comma))));
+    /// ```
+    pub fn from_values<I: IntoIterator<Item = T>>(values: I, separator: TokenReference<S>) -> Self {
+        let mut punctuated = Self::new();
+        punctuated.extend_values(values, separator);
+        punctuated
+    }
+
     /// Returns whether there's any nodes in the punctuated sequence
     /// ```rust
     /// # use full_moon::ast::punctuated::{Pair, Punctuated};
@@ -71,6 +87,33 @@ impl<T, S: AnySymbol> Punctuated<T, S> {
         self.pairs.len()
     }
 
+    /// Returns a reference to the node `T` at the given index, ignoring punctuation.
+    /// Returns `None` if the index is out of bounds.
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.push(Pair::new(1, None));
+    /// assert_eq!(punctuated.get(0), Some(&1));
+    /// assert_eq!(punctuated.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.pairs.get(index).map(Pair::value)
+    }
+
+    /// Returns a mutable reference to the node `T` at the given index, ignoring punctuation.
+    /// Returns `None` if the index is out of bounds.
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.push(Pair::new(1, None));
+    /// *punctuated.get_mut(0).unwrap() += 1;
+    /// assert_eq!(punctuated.get(0), Some(&2));
+    /// assert_eq!(punctuated.get_mut(1), None);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.pairs.get_mut(index).map(Pair::value_mut)
+    }
+
     /// Returns an iterator over references of the sequence values, ignoring punctuation
     /// ```rust
     /// # use full_moon::ast::punctuated::{Pair, Punctuated};
@@ -111,6 +154,19 @@ impl<T, S: AnySymbol> Punctuated<T, S> {
         self.pairs.into_iter()
     }
 
+    /// Returns an iterator over owned values, ignoring punctuation
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.push(Pair::new(1, None));
+    /// let mut iterator = punctuated.into_values();
+    /// assert_eq!(iterator.next(), Some(1));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn into_values(self) -> impl Iterator<Item = T> {
+        self.pairs.into_iter().map(Pair::into_value)
+    }
+
     /// Returns the first pair in the sequence
     /// ```rust
     /// # use full_moon::ast::punctuated::{Pair, Punctuated};
@@ -161,6 +217,21 @@ impl<T, S: AnySymbol> Punctuated<T, S> {
         self.pairs.iter_mut()
     }
 
+    /// Returns an iterator over the punctuation of the sequence, skipping pairs without any
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// # use full_moon::tokenizer::TokenReference;
+    /// let comma = TokenReference::symbol(", ").unwrap();
+    /// let punctuated = Punctuated::from_values(vec![1, 2, 3], comma.clone());
+    /// let mut iterator = punctuated.punctuation();
+    /// assert_eq!(iterator.next(), Some(&comma));
+    /// assert_eq!(iterator.next(), Some(&comma));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn punctuation(&self) -> impl Iterator<Item = &TokenReference<S>> {
+        self.pairs.iter().filter_map(Pair::punctuation)
+    }
+
     /// Pops off the last pair if it isn't empty
     /// ```rust
     /// # use full_moon::ast::punctuated::{Pair, Punctuated};
@@ -172,6 +243,86 @@ impl<T, S: AnySymbol> Punctuated<T, S> {
         self.pairs.pop()
     }
 
+    /// Removes the pair at the given index, returning it if it exists.
+    /// If the last pair is removed, the trailing punctuation of the new last pair is removed
+    /// so that the sequence stays valid.
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.push(Pair::new(1, None));
+    /// assert_eq!(punctuated.remove(1), None);
+    /// assert_eq!(punctuated.remove(0), Some(Pair::new(1, None)));
+    /// assert!(punctuated.is_empty());
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<Pair<T, S>> {
+        if index >= self.pairs.len() {
+            return None;
+        }
+
+        let removed = self.pairs.remove(index);
+
+        if index == self.pairs.len() && removed.punctuation().is_none() {
+            if let Some(last_pair) = self.pairs.pop() {
+                self.pairs.push(Pair::End(last_pair.into_value()));
+            }
+        }
+
+        Some(removed)
+    }
+
+    /// Retains only the nodes `T` for which the given predicate returns `true`.
+    /// If the last remaining pair has trailing punctuation, it is removed so that the sequence stays valid.
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.push(Pair::new(1, None));
+    /// punctuated.push(Pair::new(2, None));
+    /// punctuated.retain(|value| *value != 2);
+    /// assert_eq!(punctuated.pop(), Some(Pair::new(1, None)));
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.pairs.retain(|pair| f(pair.value()));
+
+        if let Some(Pair::Punctuated(..)) = self.pairs.last() {
+            let last_pair = self.pairs.pop().unwrap();
+            self.pairs.push(Pair::End(last_pair.into_value()));
+        }
+    }
+
+    /// Removes every pair from the sequence
+    /// ```rust
+    /// # use full_moon::ast::punctuated::{Pair, Punctuated};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.push(Pair::new(1, None));
+    /// punctuated.clear();
+    /// assert!(punctuated.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.pairs.clear();
+    }
+
+    /// Shortens the sequence, keeping the first `len` nodes `T` and dropping the rest.
+    /// If the new last pair has punctuation, it is removed so that the sequence stays valid.
+    /// Does nothing if `len` is greater than or equal to the length of the sequence.
+    /// ```rust
+    /// # use full_moon::{ast::punctuated::{Pair, Punctuated}, tokenizer::TokenReference};
+    /// let mut punctuated = Punctuated::from_values(vec![1, 2, 3], TokenReference::symbol(", ").unwrap());
+    /// punctuated.truncate(2);
+    /// assert_eq!(punctuated.pop(), Some(Pair::new(2, None)));
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.pairs.len() {
+            return;
+        }
+
+        self.pairs.truncate(len);
+
+        if let Some(Pair::Punctuated(..)) = self.pairs.last() {
+            let last_pair = self.pairs.pop().unwrap();
+            self.pairs.push(Pair::End(last_pair.into_value()));
+        }
+    }
+
     /// Pushes a new pair onto the sequence
     /// ```rust
     /// # use full_moon::ast::punctuated::{Pair, Punctuated};
@@ -199,6 +350,96 @@ impl<T, S: AnySymbol> Punctuated<T, S> {
             .push(Pair::Punctuated(last_pair.into_value(), punctuation));
         self.pairs.push(Pair::new(value, None));
     }
+
+    /// Pushes every node `T` from the iterator onto the end of the sequence, placing a clone of
+    /// `separator` between each of them. If the sequence wasn't empty, the separator is also
+    /// placed onto the previously last element, unless it already had punctuation.
+    /// The last value will have no punctuation.
+    /// ```rust
+    /// # use full_moon::{ast::punctuated::{Pair, Punctuated}, tokenizer::TokenReference};
+    /// let comma = TokenReference::symbol(", ").unwrap();
+    /// let mut punctuated = Punctuated::from_values(vec![1], comma.clone());
+    /// punctuated.extend_values(vec![2, 3], comma.clone());
+    /// assert_eq!(punctuated.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(punctuated.last(), Some(&Pair::new(3, None)));
+    /// ```
+    pub fn extend_values<I: IntoIterator<Item = T>>(
+        &mut self,
+        values: I,
+        separator: TokenReference<S>,
+    ) {
+        for value in values {
+            if let Some(Pair::End(_)) = self.pairs.last() {
+                let last_pair = self.pairs.pop().unwrap();
+                self.pairs
+                    .push(Pair::Punctuated(last_pair.into_value(), separator.clone()));
+            }
+
+            self.pairs.push(Pair::End(value));
+        }
+    }
+
+    /// Inserts a new node `T` at the given index, using the given punctuation as its separator.
+    /// Every element other than the last will have punctuation afterwards, so inserting at the end
+    /// of the sequence will move the punctuation onto the previously last element instead.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    /// ```rust
+    /// # use full_moon::{ast::punctuated::{Pair, Punctuated}, tokenizer::TokenReference};
+    /// let mut punctuated = Punctuated::new();
+    /// punctuated.insert(0, 1, TokenReference::symbol(", ").unwrap());
+    /// assert_eq!(punctuated.last(), Some(&Pair::new(1, None)));
+    ///
+    /// punctuated.insert(1, 3, TokenReference::symbol(", ").unwrap());
+    /// punctuated.insert(1, 2, TokenReference::symbol(", ").unwrap());
+    /// assert_eq!(punctuated.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(punctuated.last(), Some(&Pair::new(3, None)));
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T, punctuation: TokenReference<S>) {
+        assert!(
+            index <= self.pairs.len(),
+            "insertion index (is {index}) should be <= len (is {})",
+            self.pairs.len()
+        );
+
+        if index < self.pairs.len() {
+            self.pairs
+                .insert(index, Pair::Punctuated(value, punctuation));
+            return;
+        }
+
+        match self.pairs.pop() {
+            Some(Pair::End(last_value)) => {
+                self.pairs.push(Pair::Punctuated(last_value, punctuation));
+            }
+
+            Some(last_pair) => self.pairs.push(last_pair),
+
+            None => {}
+        }
+
+        self.pairs.push(Pair::End(value));
+    }
+
+    /// Maps a `Punctuated<T>` to a `Punctuated<U>` by applying a function to every value,
+    /// while preserving the punctuation between them. See [`Pair::map`].
+    /// ```rust
+    /// # use full_moon::{ast::punctuated::{Pair, Punctuated}, tokenizer::TokenReference};
+    /// let punctuated = Punctuated::from_values(vec![1, 2], TokenReference::symbol(", ").unwrap());
+    /// let mut doubled = punctuated.map(|value| value * 2);
+    /// assert_eq!(doubled.pop(), Some(Pair::new(4, None)));
+    /// assert_eq!(doubled.pop(), Some(Pair::new(2, Some(TokenReference::symbol(", ").unwrap()))));
+    /// ```
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Punctuated<U, S> {
+        Punctuated {
+            pairs: self
+                .pairs
+                .into_iter()
+                .map(|pair| pair.map(&mut f))
+                .collect(),
+        }
+    }
 }
 
 impl<T, S: AnySymbol> Default for Punctuated<T, S> {
@@ -243,6 +484,37 @@ impl<V, T: VisitMut<V>, S: AnySymbol> VisitMut<V> for Punctuated<T, S> {
     }
 }
 
+impl<V, B, T: TryVisit<V, B>, S: AnySymbol> TryVisit<V, B> for Punctuated<T, S>
+where
+    TokenReference<S>: TryVisit<V, B>,
+{
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        self.pairs.try_visit(visitor)
+    }
+}
+
+impl<T, S: AnySymbol> std::ops::Index<usize> for Punctuated<T, S> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, S: AnySymbol> std::ops::IndexMut<usize> for Punctuated<T, S> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
 impl<T, S: AnySymbol> std::iter::Extend<Pair<T, S>> for Punctuated<T, S> {
     fn extend<I: IntoIterator<Item = Pair<T, S>>>(&mut self, iter: I) {
         self.pairs.extend(iter);
@@ -461,7 +733,7 @@ impl<T: Node<S>, S: AnySymbol> Node<S> for Pair<T, S> {
         match self {
             Pair::Punctuated(node, separator) => {
                 let mut items = node.tokens().items;
-                items.push(TokenItem::TokenReference(separator));
+                items.push_back(TokenItem::TokenReference(separator));
 
                 Tokens { items }
             }
@@ -493,3 +765,18 @@ impl<V, T: VisitMut<V>, S: AnySymbol> VisitMut<V> for Pair<T, S> {
         }
     }
 }
+
+impl<V, B, T: TryVisit<V, B>, S: AnySymbol> TryVisit<V, B> for Pair<T, S>
+where
+    TokenReference<S>: TryVisit<V, B>,
+{
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        match self {
+            Pair::End(value) => value.try_visit(visitor),
+            Pair::Punctuated(value, punctuation) => {
+                value.try_visit(visitor)?;
+                punctuation.try_visit(visitor)
+            }
+        }
+    }
+}
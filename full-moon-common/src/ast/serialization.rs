@@ -0,0 +1,181 @@
+use super::{Ast, BinOp, Return, UnOp};
+use crate::symbols::AnySymbol;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt;
+
+/// The version of the format written by [`Ast::to_json`] and [`Ast::to_bytes`].
+/// This is increased whenever the shape of the AST changes in a way that makes previously
+/// serialized trees unreadable, so that they can be rejected rather than misread.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct VersionedAst<'a, T> {
+    schema_version: u32,
+    ast: &'a T,
+}
+
+#[derive(Deserialize)]
+struct SchemaVersion {
+    schema_version: u32,
+}
+
+#[derive(Deserialize)]
+struct OwnedVersionedAst<T> {
+    ast: T,
+}
+
+/// An error that occurs when reading a serialized [`Ast`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SerializationError {
+    /// The data was written with a different [`SCHEMA_VERSION`] than the current one
+    SchemaVersion {
+        /// The current schema version
+        expected: u32,
+        /// The schema version the data was written with
+        found: u32,
+    },
+
+    /// The data is not valid JSON, or does not have the shape of an AST
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+
+    /// The data is not valid MessagePack, or does not have the shape of an AST
+    #[cfg(feature = "binary")]
+    Binary(rmp_serde::decode::Error),
+}
+
+impl fmt::Display for SerializationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SerializationError::SchemaVersion { expected, found } => write!(
+                formatter,
+                "ast was serialized with schema version {found}, but version {expected} is expected"
+            ),
+            #[cfg(feature = "json")]
+            SerializationError::Json(error) => write!(formatter, "invalid ast json: {error}"),
+            #[cfg(feature = "binary")]
+            SerializationError::Binary(error) => write!(formatter, "invalid ast bytes: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for SerializationError {
+    fn from(error: serde_json::Error) -> Self {
+        SerializationError::Json(error)
+    }
+}
+
+#[cfg(feature = "binary")]
+impl From<rmp_serde::decode::Error> for SerializationError {
+    fn from(error: rmp_serde::decode::Error) -> Self {
+        SerializationError::Binary(error)
+    }
+}
+
+fn check_schema_version(schema_version: u32) -> Result<(), SerializationError> {
+    if schema_version == SCHEMA_VERSION {
+        Ok(())
+    } else {
+        Err(SerializationError::SchemaVersion {
+            expected: SCHEMA_VERSION,
+            found: schema_version,
+        })
+    }
+}
+
+impl<S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>> Ast<S, B, U, R> {
+    /// Serializes the Ast into JSON, tagged with the current [`SCHEMA_VERSION`].
+    /// Use [`Ast::from_json`] to read it back.
+    /// Only available when the "json" feature flag is enabled.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ast = full_moon::parse("local x = 1").map_err(|_| "couldn't parse")?;
+    /// let json = ast.to_json();
+    /// assert_eq!(full_moon::print(&full_moon::ast::Ast::from_json(&json)?), "local x = 1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String
+    where
+        Self: Serialize,
+    {
+        serde_json::to_string(&VersionedAst {
+            schema_version: SCHEMA_VERSION,
+            ast: self,
+        })
+        .expect("serializing an ast should never fail")
+    }
+
+    /// Deserializes an Ast from JSON written by [`Ast::to_json`].
+    /// Only available when the "json" feature flag is enabled.
+    ///
+    /// # Errors
+    /// Returns [`SerializationError::SchemaVersion`] if the JSON was written with a different
+    /// [`SCHEMA_VERSION`], such as by an older version of full-moon, without attempting to read the
+    /// tree itself. Returns [`SerializationError::Json`] if the JSON is otherwise invalid.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, SerializationError>
+    where
+        Self: DeserializeOwned,
+    {
+        let SchemaVersion { schema_version } = serde_json::from_str(json)?;
+        check_schema_version(schema_version)?;
+
+        let OwnedVersionedAst { ast } = serde_json::from_str(json)?;
+        Ok(ast)
+    }
+
+    /// Serializes the Ast into a compact binary format, tagged with the current [`SCHEMA_VERSION`].
+    /// This is much smaller and faster to read than JSON, making it suitable for caching parsed trees.
+    /// Use [`Ast::from_bytes`] to read it back.
+    /// Only available when the "binary" feature flag is enabled.
+    ///
+    /// The format is [MessagePack](https://msgpack.org), with structs written as maps.
+    /// Formats that aren't self-describing, such as bincode, can't be used, as tokens are tagged
+    /// by their type and fields such as an empty `last_stmt` are left out when serializing.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ast = full_moon::parse("local x = 1").map_err(|_| "couldn't parse")?;
+    /// let bytes = ast.to_bytes();
+    /// assert_eq!(full_moon::print(&full_moon::ast::Ast::from_bytes(&bytes)?), "local x = 1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> Vec<u8>
+    where
+        Self: Serialize,
+    {
+        rmp_serde::to_vec_named(&VersionedAst {
+            schema_version: SCHEMA_VERSION,
+            ast: self,
+        })
+        .expect("serializing an ast should never fail")
+    }
+
+    /// Deserializes an Ast from bytes written by [`Ast::to_bytes`].
+    /// Only available when the "binary" feature flag is enabled.
+    ///
+    /// # Errors
+    /// Returns [`SerializationError::SchemaVersion`] if the bytes were written with a different
+    /// [`SCHEMA_VERSION`], without attempting to read the tree itself.
+    /// Returns [`SerializationError::Binary`] if the bytes are otherwise invalid.
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError>
+    where
+        Self: DeserializeOwned,
+    {
+        let SchemaVersion { schema_version } = rmp_serde::from_slice(bytes)?;
+        check_schema_version(schema_version)?;
+
+        let OwnedVersionedAst { ast } = rmp_serde::from_slice(bytes)?;
+        Ok(ast)
+    }
+}
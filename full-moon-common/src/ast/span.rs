@@ -10,11 +10,13 @@ use crate::{
     node::{Node, Tokens},
     symbols::AnySymbol,
     tokenizer::{Position, TokenReference},
+    visitors::TryVisit,
 };
 
 use full_moon_derive::Visit;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
 
 /// A contained span with the beginning and ending bounds.
 /// Refer to the [module documentation](index.html) for more details.
@@ -37,6 +39,30 @@ impl<S: AnySymbol> ContainedSpan<S> {
     pub fn tokens(&self) -> (&TokenReference<S>, &TokenReference<S>) {
         (&self.tokens.0, &self.tokens.1)
     }
+
+    /// The opening bound, such as `(` in `(...)`
+    pub fn open(&self) -> &TokenReference<S> {
+        &self.tokens.0
+    }
+
+    /// The closing bound, such as `)` in `(...)`
+    pub fn close(&self) -> &TokenReference<S> {
+        &self.tokens.1
+    }
+
+    /// Returns a new ContainedSpan with the given opening bound
+    pub fn with_open(self, open: TokenReference<S>) -> Self {
+        Self {
+            tokens: (open, self.tokens.1),
+        }
+    }
+
+    /// Returns a new ContainedSpan with the given closing bound
+    pub fn with_close(self, close: TokenReference<S>) -> Self {
+        Self {
+            tokens: (self.tokens.0, close),
+        }
+    }
 }
 
 impl<S: AnySymbol> Node<S> for ContainedSpan<S> {
@@ -57,4 +83,45 @@ impl<S: AnySymbol> Node<S> for ContainedSpan<S> {
     }
 }
 
+impl<V, B, S: AnySymbol> TryVisit<V, B> for ContainedSpan<S>
+where
+    TokenReference<S>: TryVisit<V, B>,
+{
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        self.tokens.try_visit(visitor)
+    }
+}
+
 // impl Sealed for ContainedSpan {}
+
+/// Returns the range covering both nodes, from the earliest start position to the latest end position.
+/// Useful for reporting a diagnostic over a region spanning several nodes.
+/// If only one of the nodes has a position, its position is used.
+/// Returns `None` if neither node has a start or end position.
+///
+/// ```rust
+/// # use full_moon::ast::span;
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("local x = 1\nlocal y = 2")?;
+/// let stmts = ast.nodes().stmts().collect::<Vec<_>>();
+/// let (start, end) = span::merge(stmts[1], stmts[0]).unwrap();
+/// assert_eq!((start.bytes(), end.bytes()), (0, 23));
+/// # Ok(())
+/// # }
+/// ```
+pub fn merge<S: AnySymbol>(a: &impl Node<S>, b: &impl Node<S>) -> Option<(Position, Position)> {
+    fn combine(
+        a: Option<Position>,
+        b: Option<Position>,
+        f: fn(Position, Position) -> Position,
+    ) -> Option<Position> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    let start = combine(a.start_position(), b.start_position(), std::cmp::min)?;
+    let end = combine(a.end_position(), b.end_position(), std::cmp::max)?;
+    Some((start, end))
+}
@@ -2,4 +2,6 @@ use crate::{lexer::Lexer, symbols::AnySymbol};
 
 pub trait Language<S: AnySymbol> {
     type Lex: Lexer<S>;
+    /// The versions of the language to parse as, which can be chosen at runtime
+    type Version: Copy + Default;
 }
@@ -1,4 +1,5 @@
 use crate::{tokenizer::{Token, TokenReference, TokenizerError, Position}, symbols::AnySymbol};
+use std::marker::PhantomData;
 
 pub trait Lexer<S: AnySymbol> {
     /// Creates a new Lexer from the given source string.
@@ -14,6 +15,20 @@ pub trait Lexer<S: AnySymbol> {
     /// Returns the next token.
     fn peek(&self) -> Option<&LexerResult<TokenReference<S>>>;
 
+    /// Returns the token `n` tokens ahead of the current token, processing any tokens needed to
+    /// find it. `peek_nth(0)` is the same as [`current`](Lexer::current), and `peek_nth(1)` is the
+    /// same as [`peek`](Lexer::peek). Returns `None` if the source string ends before then.
+    /// Takes `&mut self` as tokens past the next one have not been processed yet.
+    /// By default, lexers can't look further ahead than the next token, and return `None` for
+    /// anything past it.
+    fn peek_nth(&mut self, n: usize) -> Option<&LexerResult<TokenReference<S>>> {
+        match n {
+            0 => self.current(),
+            1 => self.peek(),
+            _ => None,
+        }
+    }
+
     /// Consumes the current token and returns the next token.
     fn consume(&mut self) -> Option<LexerResult<TokenReference<S>>>;
 
@@ -22,6 +37,65 @@ pub trait Lexer<S: AnySymbol> {
 
     /// Processes and returns the next token in the source string, ignoring trivia.
     fn process_next(&mut self) -> Option<LexerResult<Token<S>>>;
+
+    /// Returns an iterator that consumes the lexer, yielding every token left in the source
+    /// string with its trivia, up to and including the EOF token.
+    fn tokens_iter(self) -> LexerIter<S, Self>
+    where
+        Self: Sized,
+    {
+        LexerIter {
+            lexer: self,
+            symbol: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the tokens of a [`Lexer`], returned by [`Lexer::tokens_iter`].
+/// Every token is yielded the same way as [`Lexer::consume`] would return it.
+pub struct LexerIter<S: AnySymbol, L: Lexer<S>> {
+    lexer: L,
+    symbol: PhantomData<S>,
+}
+
+impl<S: AnySymbol, L: Lexer<S>> LexerIter<S, L> {
+    /// Returns the lexer, with the tokens yielded so far consumed
+    pub fn into_lexer(self) -> L {
+        self.lexer
+    }
+
+    /// Returns an iterator over only the significant tokens, the ones that aren't trivia such as
+    /// whitespace and comments. Unlike [`Lexer::process_next`], which processes the source string
+    /// directly and bypasses the current and peeked tokens, this yields exactly the tokens
+    /// [`Lexer::consume`] would, just without their leading and trailing trivia.
+    pub fn significant(self) -> impl Iterator<Item = LexerResult<Token<S>>> {
+        self.filter_map(|result| {
+            let result = match result {
+                LexerResult::Ok(token_reference) => LexerResult::Ok(token_reference.token),
+                LexerResult::Recovered(token_reference, errors) => {
+                    LexerResult::Recovered(token_reference.token, errors)
+                }
+                LexerResult::Fatal(errors) => return Some(LexerResult::Fatal(errors)),
+            };
+
+            match &result {
+                LexerResult::Ok(token) | LexerResult::Recovered(token, _)
+                    if token.token_type().is_trivia() =>
+                {
+                    None
+                }
+                _ => Some(result),
+            }
+        })
+    }
+}
+
+impl<S: AnySymbol, L: Lexer<S>> Iterator for LexerIter<S, L> {
+    type Item = LexerResult<TokenReference<S>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lexer.consume()
+    }
 }
 
 /// The result of a lexer operation.
@@ -150,3 +224,7 @@ pub fn is_identifier_start(character: char) -> bool {
     matches!(character, 'a'..='z' | 'A'..='Z' | '_')
 }
 
+pub fn is_identifier_continue(character: char) -> bool {
+    is_identifier_start(character) || character.is_ascii_digit()
+}
+
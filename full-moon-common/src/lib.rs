@@ -35,6 +35,27 @@ impl<S: symbols::AnySymbol> Error<S> {
             Error::TokenizerError(error) => error.range(),
         }
     }
+
+    /// Returns how severe the error is.
+    /// Errors caused by a missing token, which the parser usually recovers from by inserting a
+    /// phantom token, are a [`ErrorSeverity::Warning`]. Every other error is an [`ErrorSeverity::Error`].
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            Error::AstError(error) if error.is_missing_token() => ErrorSeverity::Warning,
+            Error::AstError(_) | Error::TokenizerError(_) => ErrorSeverity::Error,
+        }
+    }
+}
+
+/// How severe an [`Error`] is, such as for mapping errors to editor diagnostics
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ErrorSeverity {
+    /// The code had a problem that was recovered from, such as a missing token being replaced by a
+    /// phantom token. The partial AST can still be printed, though it won't match the code exactly.
+    Warning,
+    /// The code had a problem that could not be recovered from, such as an unexpected token
+    Error,
 }
 
 impl<S: symbols::AnySymbol> std::fmt::Display for Error<S> {
@@ -1,9 +1,13 @@
 use crate::{
-    ast::Ast,
+    ast::{Ast, BinOp, Return, UnOp},
     tokenizer::{Position, Token, TokenReference},
     symbols::AnySymbol,
 };
-use std::fmt;
+use std::{
+    collections::{hash_map::DefaultHasher, VecDeque},
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 /// Used to represent nodes such as tokens or function definitions
 ///
@@ -28,6 +32,25 @@ pub trait Node<S: AnySymbol> {
         Some((self.start_position()?, self.end_position()?))
     }
 
+    /// Whether the given position lies within the range of a node, inclusively.
+    /// Returns false if the range of the node can't be determined
+    fn contains_position(&self, position: Position) -> bool {
+        match self.range() {
+            Some((start, end)) => start <= position && position <= end,
+            None => false,
+        }
+    }
+
+    /// An iterator over every descendant of a node, depth-first and in source order.
+    /// Every node is yielded before its own descendants, and token references are yielded
+    /// in the same order as [`Node::tokens`] would yield them.
+    /// Fields holding containers such as `Option`, `Vec`, or `Punctuated` are yielded as nodes too.
+    fn descendants(&self) -> Descendants<S> {
+        Descendants {
+            items: self.tokens().items,
+        }
+    }
+
     /// The tokens surrounding a node that are ignored and not accessible through the node's own accessors.
     /// Use this if you want to get surrounding comments or whitespace.
     /// Returns a tuple of the leading and trailing trivia.
@@ -47,6 +70,89 @@ pub trait Node<S: AnySymbol> {
             },
         )
     }
+
+    /// The text of the leading trivia of a node, such as the comments and indentation before it.
+    /// See [`Node::surrounding_trivia`].
+    fn leading_trivia_text(&self) -> String {
+        let (leading, _) = self.surrounding_trivia();
+        leading.into_iter().map(ToString::to_string).collect()
+    }
+
+    /// The text of the trailing trivia of a node, such as a comment and newline after it.
+    /// See [`Node::surrounding_trivia`].
+    fn trailing_trivia_text(&self) -> String {
+        let (_, trailing) = self.surrounding_trivia();
+        trailing.into_iter().map(ToString::to_string).collect()
+    }
+
+    /// The exact source code a node spans, from the start of its first token to the end of its last token.
+    /// Unlike the `Display` implementation, this doesn't include the leading trivia of the first token
+    /// or the trailing trivia of the last token, so `foo.bar` in `x = foo.bar -- comment` is just `foo.bar`.
+    /// Trivia between the tokens of the node, such as comments inside of a function, is kept.
+    fn text(&self) -> String {
+        let mut text = String::new();
+        let mut tokens = self.tokens().peekable();
+        let mut first = true;
+
+        while let Some(token) = tokens.next() {
+            if !first {
+                for trivia in token.leading_trivia() {
+                    text.push_str(&trivia.to_string());
+                }
+            }
+
+            text.push_str(&token.token().to_string());
+
+            if tokens.peek().is_some() {
+                for trivia in token.trailing_trivia() {
+                    text.push_str(&trivia.to_string());
+                }
+            }
+
+            first = false;
+        }
+
+        text
+    }
+
+    /// The comments in the leading trivia of a node, such as documentation comments above a function.
+    fn leading_comments(&self) -> Vec<&Token<S>> {
+        let (leading, _) = self.surrounding_trivia();
+        leading
+            .into_iter()
+            .filter(|token| token.is_comment())
+            .collect()
+    }
+
+    /// The comments in the trailing trivia of a node, such as a comment at the end of a line.
+    fn trailing_comments(&self) -> Vec<&Token<S>> {
+        let (_, trailing) = self.surrounding_trivia();
+        trailing
+            .into_iter()
+            .filter(|token| token.is_comment())
+            .collect()
+    }
+
+    /// A hash of the tokens of a node, ignoring trivia and positions, for use as a key when
+    /// caching results for a node across edits. Nodes that are [`similar`](Node::similar) always
+    /// have the same hash, even when their whitespace or comments differ.
+    ///
+    /// The same node always has the same hash while using the same versions of full-moon and
+    /// Rust, but the hash may change between versions, so it shouldn't be stored permanently.
+    /// Nodes that aren't similar can have the same hash, both from the usual collisions of a 64 bit
+    /// hash, and because only the tokens are hashed, so nodes with the same tokens but a different
+    /// shape, such as a [`Block`](crate::ast::Block) and the only statement in it, hash the same.
+    /// Compare nodes with [`Node::similar`] when this matters.
+    fn stable_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        for token in self.tokens() {
+            token.token().token_kind().hash(&mut hasher);
+            token.token().to_string().hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
 }
 
 pub(crate) enum TokenItem<'a, S: AnySymbol> {
@@ -65,28 +171,33 @@ impl<S: AnySymbol> fmt::Debug for TokenItem<'_, S> {
     }
 }
 
+// Places the items of a node in front of the remaining items, keeping their order
+fn push_front_items<'a, S: AnySymbol>(
+    items: &mut VecDeque<TokenItem<'a, S>>,
+    node_items: VecDeque<TokenItem<'a, S>>,
+) {
+    for item in node_items.into_iter().rev() {
+        items.push_front(item);
+    }
+}
+
 /// An iterator that iterates over the tokens of a node
 /// Returned by [`Node::tokens`]
 #[derive(Default)]
 pub struct Tokens<'a, S: AnySymbol> {
-    pub(crate) items: Vec<TokenItem<'a, S>>,
+    pub(crate) items: VecDeque<TokenItem<'a, S>>,
 }
 
 impl<'a, S: AnySymbol> Iterator for Tokens<'a, S> {
     type Item = &'a TokenReference<S>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.items.is_empty() {
-            return None;
-        }
-
-        match self.items.remove(0) {
-            TokenItem::TokenReference(reference) => Some(reference),
-            TokenItem::MoreTokens(node) => {
-                let mut tokens = node.tokens();
-                tokens.items.append(&mut self.items);
-                self.items = tokens.items;
-                self.next()
+        loop {
+            match self.items.pop_front()? {
+                TokenItem::TokenReference(reference) => return Some(reference),
+                TokenItem::MoreTokens(node) => {
+                    push_front_items(&mut self.items, node.tokens().items)
+                }
             }
         }
     }
@@ -94,21 +205,84 @@ impl<'a, S: AnySymbol> Iterator for Tokens<'a, S> {
 
 impl<'a, S: AnySymbol> DoubleEndedIterator for Tokens<'a, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.items.is_empty() {
-            return None;
+        loop {
+            match self.items.pop_back()? {
+                TokenItem::TokenReference(reference) => return Some(reference),
+                TokenItem::MoreTokens(node) => self.items.append(&mut node.tokens().items),
+            }
         }
+    }
+}
 
-        match self.items.pop()? {
-            TokenItem::TokenReference(reference) => Some(reference),
+/// A descendant of a node, returned by [`Descendants`]
+#[derive(Clone, Copy)]
+pub enum Descendant<'a, S: AnySymbol> {
+    /// A node made up of further descendants, such as a statement or an expression
+    Node(&'a dyn Node<S>),
+    /// A token reference, which has no further descendants
+    TokenReference(&'a TokenReference<S>),
+}
+
+impl<S: AnySymbol> fmt::Debug for Descendant<'_, S> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Descendant::Node(_) => write!(formatter, "Descendant::Node"),
+            Descendant::TokenReference(token) => {
+                write!(formatter, "Descendant::TokenReference({token})")
+            }
+        }
+    }
+}
+
+/// An iterator that iterates over the descendants of a node
+/// Returned by [`Node::descendants`]
+#[derive(Default)]
+pub struct Descendants<'a, S: AnySymbol> {
+    items: VecDeque<TokenItem<'a, S>>,
+}
+
+impl<'a, S: AnySymbol> Iterator for Descendants<'a, S> {
+    type Item = Descendant<'a, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.items.pop_front()? {
+            TokenItem::TokenReference(reference) => Some(Descendant::TokenReference(reference)),
             TokenItem::MoreTokens(node) => {
-                let mut tokens = node.tokens();
-                self.items.append(&mut tokens.items);
-                self.next_back()
+                push_front_items(&mut self.items, node.tokens().items);
+                Some(Descendant::Node(node))
             }
         }
     }
 }
 
+/// Folds every descendant of an [`Ast`] into a single value, starting from `init`.
+/// Descendants are visited in the same order as [`Node::descendants`], starting with the root
+/// block and ending with the EOF token, which lets you accumulate results without writing a visitor.
+///
+/// ```rust
+/// # use full_moon::{node::{fold_ast, Descendant}, tokenizer::TokenType};
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("local x = y + z")?;
+/// let identifiers = fold_ast(&ast, 0, |count, descendant| match descendant {
+///     Descendant::TokenReference(token)
+///         if matches!(token.token_type(), TokenType::Identifier { .. }) => count + 1,
+///     _ => count,
+/// });
+/// assert_eq!(identifiers, 3);
+/// # Ok(())
+/// # }
+/// ```
+pub fn fold_ast<'a, S: AnySymbol, B: BinOp<S>, U: UnOp<S>, R: Return<S, B, U>, T>(
+    ast: &'a Ast<S, B, U, R>,
+    init: T,
+    f: impl FnMut(T, Descendant<'a, S>) -> T,
+) -> T {
+    Descendants {
+        items: ast.tokens().items,
+    }
+    .fold(init, f)
+}
+
 impl<S: AnySymbol> Node<S> for Ast<S> {
     fn start_position(&self) -> Option<Position> {
         self.nodes().start_position()
@@ -119,7 +293,7 @@ impl<S: AnySymbol> Node<S> for Ast<S> {
     }
 
     fn similar(&self, other: &Self) -> bool {
-        self.nodes().similar(other.nodes())
+        Ast::similar(self, other)
     }
 
     fn tokens(&self) -> Tokens<S> {
@@ -196,7 +370,7 @@ impl<S: AnySymbol> Node<S> for TokenReference<S> {
 
     fn tokens(&self) -> Tokens<S> {
         Tokens {
-            items: vec![TokenItem::TokenReference(self)],
+            items: VecDeque::from([TokenItem::TokenReference(self)]),
         }
     }
 }
@@ -25,40 +25,45 @@ macro_rules! symbol {
             }
 
             impl Symbol {
-                /// Given just the symbol text (no whitespace) and the Lua version,
-                /// returns the associated symbol, if it exists.
+                /// Given just the symbol text (no whitespace), returns the associated symbol, if it exists.
+                /// Symbols that are only available in some Lua versions, such as `goto`, are only
+                /// returned when the feature flag for one of those versions is enabled.
                 /// If you want a TokenReference instead, consider [`TokenReference::symbol`].
-                // rewrite todo: does this link?
                 /// ```rust
-                /// # use full_moon::{LuaVersion, tokenizer::Symbol};
-                /// assert_eq!(Symbol::from_str("local", LuaVersion::lua51()), Some(Symbol::Local));
+                /// # use full_moon::tokenizer::Symbol;
+                /// assert_eq!(Symbol::from_str("local"), Some(Symbol::Local));
+                /// assert_eq!(Symbol::from_str("+"), Some(Symbol::Plus));
+                /// assert_eq!(Symbol::from_str("foo"), None);
                 ///
                 /// # #[cfg(feature = "lua52")]
-                /// assert_eq!(Symbol::from_str("goto", LuaVersion::lua52()), Some(Symbol::Goto));
-                /// assert_eq!(Symbol::from_str("goto", LuaVersion::lua51()), None);
+                /// assert_eq!(Symbol::from_str("goto"), Some(Symbol::Goto));
+                /// # #[cfg(not(feature = "lua52"))]
+                /// assert_eq!(Symbol::from_str("goto"), None);
                 /// ```
-                #[allow(unused)] // Without any features, lua_version is unused
+                #[allow(clippy::should_implement_trait)]
                 pub fn from_str(symbol: &str) -> Option<Self> {
-                    todo!();
-                    None
-                    // match symbol {
-                    //     $(
-                    //         $(
-                    //             #[cfg(any(
-                    //                 $(feature = "" $version),+
-                    //             ))]
-                    //         )?
-                    //         $string => {
-                    //             if !crate::has_version!(lua_version, $($($version,)+)?) {
-                    //                 return None;
-                    //             }
-                    //
-                    //             Some(Self::$name)
-                    //         },
-                    //     )+
-                    //
-                    //     _ => None,
-                    // }
+                    match symbol {
+                        $(
+                            $(
+                                #[cfg(any(
+                                    $(feature = "" $version),+
+                                ))]
+                            )*
+                            $string => Some(Self::$name),
+                        )+
+
+                        _ => None,
+                    }
+                }
+            }
+
+            impl std::str::FromStr for Symbol {
+                type Err = $crate::tokenizer::TokenizerErrorType;
+
+                fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+                    Symbol::from_str(symbol).ok_or_else(|| {
+                        $crate::tokenizer::TokenizerErrorType::InvalidSymbol(symbol.to_owned())
+                    })
                 }
             }
 
@@ -80,4 +85,4 @@ macro_rules! symbol {
     };
 }
 
-pub trait AnySymbol: Clone + Ord {}
+pub trait AnySymbol: Clone + Ord + std::str::FromStr {}
@@ -7,6 +7,7 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
+    borrow::Cow,
     cmp::Ordering,
     fmt::{self, Display},
 };
@@ -35,6 +36,104 @@ impl Position {
     pub fn line(self) -> usize {
         self.line
     }
+
+    /// Returns a new position moved forward by the given amount of bytes, lines, and characters
+    pub fn with_offset(self, bytes: usize, line_delta: usize, character: usize) -> Position {
+        Position {
+            bytes: self.bytes + bytes,
+            line: self.line + line_delta,
+            character: self.character + character,
+        }
+    }
+
+    /// Returns the position right after the given text, if it were to start at this position.
+    /// Newlines move the position to the first character of the next line.
+    /// ```rust
+    /// # use full_moon::tokenizer::Position;
+    /// let start = Position { bytes: 0, line: 1, character: 1 };
+    /// let end = start.advance("local\nx");
+    /// assert_eq!((end.bytes(), end.line(), end.character()), (7, 2, 2));
+    /// ```
+    pub fn advance(self, text: &str) -> Position {
+        text.chars().fold(self, |position, character| Position {
+            bytes: position.bytes + character.len_utf8(),
+            line: position.line + usize::from(character == '\n'),
+            character: if character == '\n' {
+                1
+            } else {
+                position.character + 1
+            },
+        })
+    }
+
+    /// Converts the position to the 0-based line and UTF-16 column used by the
+    /// [Language Server Protocol](https://microsoft.github.io/language-server-protocol/),
+    /// given the source code the position is in. Characters outside of the Basic Multilingual
+    /// Plane, such as most emoji, take up two UTF-16 code units, but only one character.
+    /// Only available when the "lsp" feature flag is enabled.
+    ///
+    /// ```rust
+    /// # use full_moon::tokenizer::Position;
+    /// let source = "local x = 1\nprint('😀', x)";
+    /// let x = Position { bytes: 26, line: 2, character: 12 };
+    /// assert_eq!(x.to_utf16(source), (1, 12));
+    /// ```
+    #[cfg(feature = "lsp")]
+    pub fn to_utf16(&self, source: &str) -> (u32, u32) {
+        let line_text = source
+            .split('\n')
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or("");
+        let mut characters = line_text.chars();
+
+        // Characters past the end of the line are counted as one code unit each
+        let column: usize = (1..self.character)
+            .map(|_| characters.next().map_or(1, char::len_utf16))
+            .sum();
+
+        (self.line.saturating_sub(1) as u32, column as u32)
+    }
+
+    /// Creates a position from the 0-based line and UTF-16 column used by the
+    /// [Language Server Protocol](https://microsoft.github.io/language-server-protocol/),
+    /// given the source code the position is in. The opposite of [`Position::to_utf16`].
+    /// Columns past the end of the line are clamped to the end of the line, and columns in the
+    /// middle of a character are moved to the start of it.
+    /// Returns `None` if the source doesn't have the given line.
+    /// Only available when the "lsp" feature flag is enabled.
+    ///
+    /// ```rust
+    /// # use full_moon::tokenizer::Position;
+    /// let source = "local x = 1\nprint('😀', x)";
+    /// let x = Position::from_utf16(source, 1, 12).unwrap();
+    /// assert_eq!((x.bytes(), x.line(), x.character()), (26, 2, 12));
+    /// ```
+    #[cfg(feature = "lsp")]
+    pub fn from_utf16(source: &str, line: u32, column: u32) -> Option<Position> {
+        let mut line_start = 0;
+        for _ in 0..line {
+            line_start += source[line_start..].find('\n')? + 1;
+        }
+
+        let mut position = Position {
+            bytes: line_start,
+            line: line as usize + 1,
+            character: 1,
+        };
+        let mut units = 0;
+
+        for character in source[line_start..].chars() {
+            if character == '\n' || units + character.len_utf16() > column as usize {
+                break;
+            }
+
+            units += character.len_utf16();
+            position.bytes += character.len_utf8();
+            position.character += 1;
+        }
+
+        Some(position)
+    }
 }
 
 impl Ord for Position {
@@ -184,6 +283,42 @@ impl<S> TokenType<S> {
             characters: "\t".repeat(tabs).into(),
         }
     }
+
+    /// Returns a whitespace `TokenType` consisting of newlines (`\n`)
+    pub fn newlines(newlines: usize) -> Self {
+        TokenType::Whitespace {
+            characters: "\n".repeat(newlines).into(),
+        }
+    }
+
+    /// Returns a whitespace `TokenType` of the given text, such as `"\n\t"`.
+    ///
+    /// # Errors
+    /// Returns [`TokenizerErrorType::UnexpectedToken`] with the first character of the text that
+    /// isn't a space, tab, carriage return, or newline.
+    /// ```rust
+    /// # use full_moon::tokenizer::{TokenType, TokenizerErrorType};
+    /// assert_eq!(
+    ///     TokenType::whitespace("\n\t"),
+    ///     Ok(TokenType::Whitespace { characters: "\n\t".into() }),
+    /// );
+    /// assert_eq!(
+    ///     TokenType::whitespace("\t-- comment"),
+    ///     Err(TokenizerErrorType::UnexpectedToken('-')),
+    /// );
+    /// ```
+    pub fn whitespace(whitespace: &str) -> Result<Self, TokenizerErrorType> {
+        if let Some(character) = whitespace
+            .chars()
+            .find(|character| !matches!(character, ' ' | '\t' | '\r' | '\n'))
+        {
+            return Err(TokenizerErrorType::UnexpectedToken(character));
+        }
+
+        Ok(TokenType::Whitespace {
+            characters: whitespace.into(),
+        })
+    }
 }
 
 /// A token such consisting of its [`Position`] and a [`TokenType`]
@@ -226,6 +361,358 @@ impl<S: AnySymbol> Token<S> {
     pub fn token_kind(&self) -> TokenKind {
         self.token_type().kind()
     }
+
+    /// Returns whether the token is a comment, either single line or multi line.
+    /// Whitespace and shebangs are trivia, but are not comments.
+    pub fn is_comment(&self) -> bool {
+        matches!(
+            self.token_type(),
+            TokenType::SingleLineComment { .. } | TokenType::MultiLineComment { .. }
+        )
+    }
+
+    /// Returns whether the token is a UTF-8 byte order mark, which is only allowed at the very
+    /// start of the code, and is kept as whitespace.
+    pub fn is_bom(&self) -> bool {
+        match self.token_type() {
+            TokenType::Whitespace { characters } => characters.as_str() == "\u{feff}",
+            _ => false,
+        }
+    }
+
+    /// Parses the value of a [`TokenType::Number`] token, returning `None` for any other token.
+    /// Decimal, exponent, hexadecimal, and binary numbers are supported, as well as digit separators.
+    /// Since the tokenizer only produces numbers valid for the enabled Lua versions, no further
+    /// validation is done here.
+    pub fn parse_number(&self) -> Option<f64> {
+        let TokenType::Number { text } = self.token_type() else {
+            return None;
+        };
+
+        let text = text.replace('_', "");
+
+        if let Some(digits) = strip_radix_prefix(&text, 'x') {
+            parse_hex_float(digits)
+        } else if let Some(digits) = strip_radix_prefix(&text, 'b') {
+            u64::from_str_radix(digits, 2)
+                .ok()
+                .map(|value| value as f64)
+        } else {
+            text.parse().ok()
+        }
+    }
+
+    /// Parses the value of a [`TokenType::Number`] token as an integer, returning `None` for any
+    /// other token, or if the number is not an integer.
+    /// Hexadecimal and binary numbers wrap around when they overflow, like they do in Lua 5.3,
+    /// while decimal numbers that overflow return `None`.
+    pub fn parse_integer(&self) -> Option<i64> {
+        let TokenType::Number { text } = self.token_type() else {
+            return None;
+        };
+
+        let text = text.replace('_', "");
+
+        if let Some(digits) = strip_radix_prefix(&text, 'x') {
+            parse_wrapping_integer(digits, 16)
+        } else if let Some(digits) = strip_radix_prefix(&text, 'b') {
+            parse_wrapping_integer(digits, 2)
+        } else {
+            text.parse().ok()
+        }
+    }
+
+    /// Returns the contents of a [`TokenType::StringLiteral`] token with its escape sequences decoded,
+    /// such as `\n`, `\65`, `\x41`, `\z`, and `\u{41}`.
+    /// Strings using brackets are returned untouched, as they have no escape sequences.
+    /// Luau interpolated strings without any expressions in them, such as `` `hello` ``, are
+    /// decoded too, including their `` \` `` and `\{` escape sequences.
+    /// Returns `None` for any other token, if an escape sequence is malformed, or if the decoded
+    /// string is not valid UTF-8.
+    pub fn string_value(&self) -> Option<Cow<str>> {
+        let (literal, interpolated) = match self.token_type() {
+            TokenType::StringLiteral {
+                literal,
+                quote_type: StringLiteralQuoteType::Brackets,
+                ..
+            } => return Some(Cow::Borrowed(literal.as_str())),
+            TokenType::StringLiteral { literal, .. } => (literal, false),
+            #[cfg(feature = "luau")]
+            TokenType::InterpolatedString {
+                literal,
+                kind: InterpolatedStringKind::Simple,
+            } => (literal, true),
+            _ => return None,
+        };
+
+        if !literal.contains('\\') {
+            return Some(Cow::Borrowed(literal.as_str()));
+        }
+
+        unescape_string(literal, interpolated).map(Cow::Owned)
+    }
+
+    /// Creates a clone of a [`TokenType::StringLiteral`] token using the given quote type,
+    /// such as turning `'a"b'` into `"a\"b"`. Quotes are escaped as needed, while every other
+    /// escape sequence is kept as it is. Converting to brackets decodes the escape sequences,
+    /// and uses the smallest depth that doesn't clash with the contents of the string.
+    ///
+    /// Returns `None` for any other token, or if the string can't be represented exactly with the
+    /// new quote type, such as a bracket string containing a newline, or a string with a leading
+    /// newline or carriage return being converted to brackets.
+    ///
+    /// ```rust
+    /// # use full_moon::tokenizer::{StringLiteralQuoteType, Token, TokenType};
+    /// let string = Token::new(TokenType::StringLiteral {
+    ///     literal: "a\"b".into(),
+    ///     multi_line_depth: 0,
+    ///     quote_type: StringLiteralQuoteType::Single,
+    /// });
+    ///
+    /// let double = string.with_quote_type(StringLiteralQuoteType::Double).unwrap();
+    /// assert_eq!(double.to_string(), r#""a\"b""#);
+    /// ```
+    pub fn with_quote_type(&self, quote_type: StringLiteralQuoteType) -> Option<Self> {
+        let TokenType::StringLiteral {
+            literal,
+            quote_type: current_quote_type,
+            ..
+        } = self.token_type()
+        else {
+            return None;
+        };
+
+        if *current_quote_type == quote_type {
+            return Some(self.clone());
+        }
+
+        let (literal, multi_line_depth) = match quote_type {
+            StringLiteralQuoteType::Brackets => bracket_string(&self.string_value()?)?,
+            StringLiteralQuoteType::Double | StringLiteralQuoteType::Single => {
+                let quote = if quote_type == StringLiteralQuoteType::Double {
+                    '"'
+                } else {
+                    '\''
+                };
+
+                if *current_quote_type == StringLiteralQuoteType::Brackets {
+                    (escape_bracket_string(literal, quote)?, 0)
+                } else {
+                    (requote_string(literal, quote), 0)
+                }
+            }
+        };
+
+        Some(Self {
+            token_type: TokenType::StringLiteral {
+                literal: literal.into(),
+                multi_line_depth,
+                quote_type,
+            },
+            ..self.clone()
+        })
+    }
+}
+
+// Decodes the escape sequences of a quoted string, or of an interpolated string, which can
+// also escape backticks and braces
+fn unescape_string(literal: &str, interpolated: bool) -> Option<String> {
+    let mut bytes = Vec::with_capacity(literal.len());
+    let mut characters = literal.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        match characters.next()? {
+            'a' => bytes.push(b'\x07'),
+            'b' => bytes.push(b'\x08'),
+            'f' => bytes.push(b'\x0C'),
+            'n' | '\n' => bytes.push(b'\n'),
+            'r' => bytes.push(b'\r'),
+            't' => bytes.push(b'\t'),
+            'v' => bytes.push(b'\x0B'),
+            '\\' => bytes.push(b'\\'),
+            '"' => bytes.push(b'"'),
+            '\'' => bytes.push(b'\''),
+            '`' if interpolated => bytes.push(b'`'),
+            '{' if interpolated => bytes.push(b'{'),
+
+            '\r' => {
+                characters.next_if_eq(&'\n');
+                bytes.push(b'\n');
+            }
+
+            'z' => {
+                while characters
+                    .next_if(|next| next.is_ascii_whitespace())
+                    .is_some()
+                {}
+            }
+
+            'x' => {
+                let high = characters.next()?.to_digit(16)?;
+                let low = characters.next()?.to_digit(16)?;
+                bytes.push((high * 16 + low) as u8);
+            }
+
+            'u' => {
+                if characters.next()? != '{' {
+                    return None;
+                }
+
+                let mut code_point = 0u32;
+                let mut digits = 0;
+
+                while let Some(digit) = characters.next_if(|next| *next != '}') {
+                    code_point = code_point
+                        .checked_mul(16)?
+                        .checked_add(digit.to_digit(16)?)?;
+                    digits += 1;
+                }
+
+                if digits == 0 || characters.next()? != '}' {
+                    return None;
+                }
+
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(
+                    char::from_u32(code_point)?
+                        .encode_utf8(&mut buffer)
+                        .as_bytes(),
+                );
+            }
+
+            digit @ '0'..='9' => {
+                let mut value = digit.to_digit(10)?;
+
+                for _ in 0..2 {
+                    match characters.next_if(char::is_ascii_digit) {
+                        Some(digit) => value = value * 10 + digit.to_digit(10)?,
+                        None => break,
+                    }
+                }
+
+                bytes.push(u8::try_from(value).ok()?);
+            }
+
+            _ => return None,
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+// Escapes the given quote in a quoted string, keeping existing escape sequences untouched
+fn requote_string(literal: &str, quote: char) -> String {
+    let mut requoted = String::with_capacity(literal.len());
+    let mut characters = literal.chars();
+
+    while let Some(character) = characters.next() {
+        if character == '\\' {
+            requoted.push(character);
+            requoted.extend(characters.next());
+        } else {
+            if character == quote {
+                requoted.push('\\');
+            }
+
+            requoted.push(character);
+        }
+    }
+
+    requoted
+}
+
+// Bracket strings have no escape sequences, so backslashes and the quote need to be escaped.
+// Newlines could be escaped too, but that would turn a multi line string into a single line.
+fn escape_bracket_string(literal: &str, quote: char) -> Option<String> {
+    let mut escaped = String::with_capacity(literal.len());
+
+    for character in literal.chars() {
+        match character {
+            '\n' | '\r' => return None,
+            '\\' => escaped.push_str("\\\\"),
+            character if character == quote => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            character => escaped.push(character),
+        }
+    }
+
+    Some(escaped)
+}
+
+// Returns the contents and depth of a bracket string with the given value.
+// A leading newline is skipped by Lua, and carriage returns are read as newlines.
+fn bracket_string(value: &str) -> Option<(String, usize)> {
+    if value.starts_with('\n') || value.contains('\r') {
+        return None;
+    }
+
+    let depth = (0..)
+        .find(|&depth| {
+            let equals = "=".repeat(depth);
+            !format!("{value}]{equals}").contains(&format!("]{equals}]"))
+        })
+        .expect("a long enough depth is always found");
+
+    Some((value.to_owned(), depth))
+}
+
+fn strip_radix_prefix(text: &str, radix_character: char) -> Option<&str> {
+    let text = text.strip_prefix('0')?;
+
+    text.strip_prefix(radix_character)
+        .or_else(|| text.strip_prefix(radix_character.to_ascii_uppercase()))
+}
+
+fn parse_hex_float(text: &str) -> Option<f64> {
+    let (mantissa_text, exponent_text) = match text.find(['p', 'P']) {
+        Some(index) => (&text[..index], Some(&text[index + 1..])),
+        None => (text, None),
+    };
+
+    let (integer_text, fraction_text) = match mantissa_text.split_once('.') {
+        Some((integer_text, fraction_text)) => (integer_text, fraction_text),
+        None => (mantissa_text, ""),
+    };
+
+    if integer_text.is_empty() && fraction_text.is_empty() {
+        return None;
+    }
+
+    let mut mantissa = 0.0;
+    for digit in integer_text.chars().chain(fraction_text.chars()) {
+        mantissa = mantissa * 16.0 + f64::from(digit.to_digit(16)?);
+    }
+
+    let mut exponent = -4 * i32::try_from(fraction_text.len()).ok()?;
+    if let Some(exponent_text) = exponent_text {
+        exponent += exponent_text.parse::<i32>().ok()?;
+    }
+
+    Some(mantissa * 2f64.powi(exponent))
+}
+
+fn parse_wrapping_integer(text: &str, radix: u32) -> Option<i64> {
+    if text.is_empty() {
+        return None;
+    }
+
+    text.chars()
+        .try_fold(0u64, |value, digit| {
+            Some(
+                value
+                    .wrapping_mul(u64::from(radix))
+                    .wrapping_add(u64::from(digit.to_digit(radix)?)),
+            )
+        })
+        .map(|value| value as i64)
 }
 
 impl<S: AnySymbol> fmt::Display for Token<S> {
@@ -246,16 +733,8 @@ impl<S: AnySymbol> fmt::Display for Token<S> {
                 multi_line_depth,
                 quote_type,
             } => {
-                if *quote_type == StringLiteralQuoteType::Brackets {
-                    write!(
-                        formatter,
-                        "[{0}[{1}]{0}]",
-                        "=".repeat(*multi_line_depth),
-                        literal
-                    )
-                } else {
-                    write!(formatter, "{0}{1}{0}", quote_type.to_string(), literal)
-                }
+                let (open, close) = quote_type.open_close(*multi_line_depth);
+                write!(formatter, "{open}{literal}{close}")
             }
             Symbol { symbol } => todo!(),//symbol.fmt(formatter),
             Whitespace { characters } => characters.fmt(formatter),
@@ -295,7 +774,7 @@ impl<S: AnySymbol> PartialOrd for Token<S> {
 }
 
 /// The kind of token. Contains no additional data.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum TokenKind {
     /// End of file, should always be the very last token
@@ -346,6 +825,38 @@ impl<S: AnySymbol> TokenReference<S> {
         }
     }
 
+    /// Creates an identifier token reference with no trivia.
+    /// The name is not checked to be a valid identifier.
+    /// ```rust
+    /// # use full_moon::tokenizer::{TokenReference, TokenType};
+    /// let identifier = TokenReference::identifier("x");
+    /// assert_eq!(identifier.token_type(), &TokenType::Identifier { identifier: "x".into() });
+    /// ```
+    pub fn identifier(name: &str) -> Self {
+        Self::new(
+            Vec::new(),
+            Token::new(TokenType::Identifier {
+                identifier: name.into(),
+            }),
+            Vec::new(),
+        )
+    }
+
+    /// Creates a number token reference with no trivia.
+    /// The text is not checked to be a valid number.
+    /// ```rust
+    /// # use full_moon::tokenizer::{TokenReference, TokenType};
+    /// let number = TokenReference::number("0xFF");
+    /// assert_eq!(number.token_type(), &TokenType::Number { text: "0xFF".into() });
+    /// ```
+    pub fn number(text: &str) -> Self {
+        Self::new(
+            Vec::new(),
+            Token::new(TokenType::Number { text: text.into() }),
+            Vec::new(),
+        )
+    }
+
     // /// Returns a symbol with the leading and trailing whitespace
     // /// Only whitespace is supported
     // /// ```rust
@@ -494,6 +1005,25 @@ impl<S: AnySymbol> TokenReference<S> {
         self.trailing_trivia.iter()
     }
 
+    /// Returns whether any of the leading trivia is a comment
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("-- hello\nlocal x = 1")?;
+    /// let local = ast.tokens().next().unwrap();
+    /// assert!(local.has_leading_comments());
+    /// assert!(!local.has_trailing_comments());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_leading_comments(&self) -> bool {
+        self.leading_trivia().any(Token::is_comment)
+    }
+
+    /// Returns whether any of the trailing trivia is a comment
+    pub fn has_trailing_comments(&self) -> bool {
+        self.trailing_trivia().any(Token::is_comment)
+    }
+
     /// Creates a clone of the current TokenReference with the new inner token, preserving trivia.
     pub fn with_token(&self, token: Token<S>) -> Self {
         Self {
@@ -503,12 +1033,86 @@ impl<S: AnySymbol> TokenReference<S> {
         }
     }
 
+    /// Creates a clone of the current TokenReference with the new token type, preserving trivia
+    /// and the positions of the inner token. Useful for renaming identifiers.
+    /// ```rust
+    /// # use full_moon::tokenizer::{TokenReference, TokenType};
+    /// let name = TokenReference::identifier("x");
+    /// let renamed = name.with_token_type(TokenType::Identifier { identifier: "y".into() });
+    /// assert_eq!(renamed.to_string(), "y");
+    /// assert_eq!(renamed.start_position(), name.start_position());
+    /// ```
+    pub fn with_token_type(&self, token_type: TokenType<S>) -> Self {
+        self.with_token(Token {
+            token_type,
+            ..self.token.clone()
+        })
+    }
+
+    /// Creates a clone of the current TokenReference with the given leading trivia
+    pub fn with_leading_trivia(&self, leading_trivia: Vec<Token<S>>) -> Self {
+        Self {
+            leading_trivia,
+            token: self.token.clone(),
+            trailing_trivia: self.trailing_trivia.clone(),
+        }
+    }
+
+    /// Creates a clone of the current TokenReference with the given trailing trivia
+    pub fn with_trailing_trivia(&self, trailing_trivia: Vec<Token<S>>) -> Self {
+        Self {
+            leading_trivia: self.leading_trivia.clone(),
+            token: self.token.clone(),
+            trailing_trivia,
+        }
+    }
+
+    /// Creates a clone of the current TokenReference whose leading trivia is a single whitespace
+    /// token of the given text, or no trivia at all if the text is empty.
+    ///
+    /// # Errors
+    /// Returns [`TokenizerErrorType::UnexpectedToken`] with the first character of the text that
+    /// isn't a space, tab, carriage return, or newline.
+    /// ```rust
+    /// # use full_moon::tokenizer::{TokenReference, TokenizerErrorType};
+    /// let name = TokenReference::identifier("x");
+    /// assert_eq!(name.with_leading_whitespace("\n\t").unwrap().to_string(), "\n\tx");
+    /// assert_eq!(
+    ///     name.with_leading_whitespace(" -- comment"),
+    ///     Err(TokenizerErrorType::UnexpectedToken('-')),
+    /// );
+    /// ```
+    pub fn with_leading_whitespace(&self, whitespace: &str) -> Result<Self, TokenizerErrorType> {
+        Ok(self.with_leading_trivia(whitespace_trivia(whitespace)?))
+    }
+
+    /// Creates a clone of the current TokenReference whose trailing trivia is a single whitespace
+    /// token of the given text, or no trivia at all if the text is empty.
+    ///
+    /// # Errors
+    /// Returns [`TokenizerErrorType::UnexpectedToken`] with the first character of the text that
+    /// isn't a space, tab, carriage return, or newline.
+    pub fn with_trailing_whitespace(&self, whitespace: &str) -> Result<Self, TokenizerErrorType> {
+        Ok(self.with_trailing_trivia(whitespace_trivia(whitespace)?))
+    }
+
     /// Checks if the token is the given symbol
     pub fn is_symbol(&self, symbol: S) -> bool {
         self.token.token_type() == &TokenType::Symbol { symbol }
     }
 }
 
+// Builds the trivia for `with_leading_whitespace` and `with_trailing_whitespace`
+fn whitespace_trivia<S: AnySymbol>(whitespace: &str) -> Result<Vec<Token<S>>, TokenizerErrorType> {
+    let token_type = TokenType::whitespace(whitespace)?;
+
+    if whitespace.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Ok(vec![Token::new(token_type)])
+}
+
 impl<S: AnySymbol> std::borrow::Borrow<Token<S>> for &TokenReference<S> {
     fn borrow(&self) -> &Token<S> {
         self
@@ -575,12 +1179,41 @@ pub enum StringLiteralQuoteType {
     Single,
 }
 
+impl StringLiteralQuoteType {
+    /// The opening and closing delimiters of a string with this quote type.
+    /// `depth` is the amount of `=` used by bracket strings, such as `[==[` and `]==]` for a depth
+    /// of 2, and is ignored for quotes. Unlike [`Display`](fmt::Display), this works for brackets.
+    ///
+    /// ```rust
+    /// # use full_moon::tokenizer::StringLiteralQuoteType;
+    /// assert_eq!(
+    ///     StringLiteralQuoteType::Brackets.open_close(1),
+    ///     ("[=[".to_owned(), "]=]".to_owned()),
+    /// );
+    /// assert_eq!(
+    ///     StringLiteralQuoteType::Double.open_close(0),
+    ///     ("\"".to_owned(), "\"".to_owned()),
+    /// );
+    /// ```
+    pub fn open_close(&self, depth: usize) -> (String, String) {
+        match self {
+            StringLiteralQuoteType::Brackets => {
+                let equals = "=".repeat(depth);
+                (format!("[{equals}["), format!("]{equals}]"))
+            }
+            StringLiteralQuoteType::Double => ("\"".to_owned(), "\"".to_owned()),
+            StringLiteralQuoteType::Single => ("'".to_owned(), "'".to_owned()),
+        }
+    }
+}
+
 impl fmt::Display for StringLiteralQuoteType {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             // Brackets cannot be properly displayed, as not only do they have
             // variable depth (`=`), but also they don't open the same as
             // they end, meaning this can't really be used for display purposes.
+            // `open_close` should be used instead.
             StringLiteralQuoteType::Brackets => Err(fmt::Error),
             StringLiteralQuoteType::Double => "\"".fmt(formatter),
             StringLiteralQuoteType::Single => "'".fmt(formatter),
@@ -664,6 +1297,20 @@ impl fmt::Display for TokenizerErrorType {
     }
 }
 
+/// Returns every token in the code, including trivia and the final [`TokenType::Eof`] token,
+/// without building an [`Ast`](crate::ast::Ast). Useful for tools such as syntax highlighters
+/// that don't need the parse tree.
+///
+/// # Errors
+/// If the code cannot be fully tokenized, every [`TokenizerError`] encountered is returned.
+pub fn tokens<S: AnySymbol, L: Language<S>>(
+    code: &str,
+) -> Result<Vec<Token<S>>, Vec<TokenizerError>> {
+    match L::Lex::new(code).collect() {
+        LexerResult::Ok(tokens) => Ok(tokens),
+        LexerResult::Recovered(_, errors) | LexerResult::Fatal(errors) => Err(errors),
+    }
+}
 
 // Used by serde
 fn is_usize_zero(input: &usize) -> bool {
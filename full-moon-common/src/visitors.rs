@@ -1,3 +1,6 @@
+use crate::tokenizer::Position;
+use std::ops::ControlFlow;
+
 #[macro_export]
 macro_rules! create_visitor {
     (ast: {
@@ -95,6 +98,14 @@ macro_rules! create_visitor {
                 }
             }
 
+            /// Visit the nodes of an [`Ast`](crate::ast::Ast), then update the positions of every token
+            /// with [`Ast::update_positions`](crate::ast::Ast::update_positions).
+            /// Use this over [`VisitorMut::visit_ast`] if the visitor changes the text of any tokens,
+            /// such as when renaming identifiers, and the positions are needed afterwards.
+            fn visit_ast_update_positions(&mut self, ast: Ast) -> Ast where Self: Sized {
+                self.visit_ast(ast).update_positions()
+            }
+
             paste::item! {
                 $(
                     #[allow(missing_docs)]
@@ -144,9 +155,299 @@ macro_rules! create_visitor {
                 )+
             )+
         }
+
+        /// A trait that implements functions to listen for specific nodes/tokens, like [`Visitor`],
+        /// but which can stop the traversal early.
+        /// Returning [`ControlFlow::Break`](std::ops::ControlFlow::Break) from any function stops
+        /// the traversal from descending any further, and the break value is returned from
+        /// [`visit_ast`](TryVisitor::visit_ast).
+        ///
+        /// ```rust
+        /// # use full_moon::ast;
+        /// # use full_moon::visitors::*;
+        /// # use std::ops::ControlFlow;
+        /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+        /// // A visitor that finds the first return statement
+        /// struct FirstReturnVisitor;
+        ///
+        /// impl TryVisitor for FirstReturnVisitor {
+        ///     type Break = String;
+        ///
+        ///     fn visit_return(&mut self, node: &ast::Return) -> ControlFlow<String> {
+        ///         ControlFlow::Break(node.to_string())
+        ///     }
+        /// }
+        ///
+        /// let ast = full_moon::parse("local function f() return 1 end return 2")?;
+        /// assert_eq!(
+        ///     FirstReturnVisitor.visit_ast(&ast),
+        ///     ControlFlow::Break("return 1 ".to_string())
+        /// );
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub trait TryVisitor {
+            /// The value returned when the traversal is stopped early
+            type Break;
+
+            /// Visit the nodes of an [`Ast`](crate::ast::Ast), stopping at the first
+            /// [`ControlFlow::Break`](std::ops::ControlFlow::Break)
+            fn visit_ast(&mut self, ast: &Ast) -> std::ops::ControlFlow<Self::Break> where Self: Sized {
+                ast.nodes().try_visit(self)?;
+                ast.eof().try_visit(self)
+            }
+
+            paste::item! {
+                $(
+                    #[allow(missing_docs)]
+                    fn $visit_name(&mut self, _node: &$ast_type) -> std::ops::ControlFlow<Self::Break> {
+                        std::ops::ControlFlow::Continue(())
+                    }
+                    #[allow(missing_docs)]
+                    fn [<$visit_name _end>](&mut self, _node: &$ast_type) -> std::ops::ControlFlow<Self::Break> {
+                        std::ops::ControlFlow::Continue(())
+                    }
+                )+
+
+                $(
+                    $(
+                        #[$meta]
+                        #[allow(missing_docs)]
+                        fn $meta_visit_name(&mut self, _node: &$meta_ast_type) -> std::ops::ControlFlow<Self::Break> {
+                            std::ops::ControlFlow::Continue(())
+                        }
+                        #[$meta]
+                        #[allow(missing_docs)]
+                        fn [<$meta_visit_name _end>](&mut self, _node: &$meta_ast_type) -> std::ops::ControlFlow<Self::Break> {
+                            std::ops::ControlFlow::Continue(())
+                        }
+                    )+
+                )+
+            }
+
+            $(
+                #[allow(missing_docs)]
+                fn $visit_token(&mut self, _token: &Token) -> std::ops::ControlFlow<Self::Break> {
+                    std::ops::ControlFlow::Continue(())
+                }
+            )+
+
+            $(
+                $(
+                    #[$token_meta]
+                    #[allow(missing_docs)]
+                    fn $meta_visit_token(&mut self, _token: &Token) -> std::ops::ControlFlow<Self::Break> {
+                        std::ops::ControlFlow::Continue(())
+                    }
+                )+
+            )+
+        }
+
+        /// A trait that implements functions to listen for specific nodes/tokens, like [`Visitor`],
+        /// but which also receives the path of ancestors leading to each node/token.
+        ///
+        /// The path starts at the outermost [`Block`](crate::ast::Block) of the [`Ast`](crate::ast::Ast),
+        /// and ends with the direct parent of the node being visited. A node is part of the path
+        /// from when it is visited until its `_end` function is called, so the node itself is
+        /// never part of its own path. Token references are never part of a path.
+        ///
+        /// ```rust
+        /// # use full_moon::ast;
+        /// # use full_moon::visitors::*;
+        /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+        /// // A visitor that finds every local assignment made directly inside of a function
+        /// #[derive(Default)]
+        /// struct FunctionLocalsVisitor {
+        ///     names: Vec<String>,
+        /// }
+        ///
+        /// impl PathVisitor for FunctionLocalsVisitor {
+        ///     fn visit_local_assignment(
+        ///         &mut self,
+        ///         local_assignment: &ast::LocalAssignment,
+        ///         path: &[NodePathSegment<NodeKind>],
+        ///     ) {
+        ///         let nearest_body = path.iter().rev().find(|segment| {
+        ///             matches!(segment.kind(), NodeKind::VisitFunctionBody | NodeKind::VisitDo)
+        ///         });
+        ///
+        ///         if nearest_body.map(NodePathSegment::kind) == Some(NodeKind::VisitFunctionBody) {
+        ///             self.names.push(local_assignment.names().to_string());
+        ///         }
+        ///     }
+        /// }
+        ///
+        /// let mut visitor = FunctionLocalsVisitor::default();
+        /// visitor.visit_ast(&full_moon::parse("local a = 1 function f() local b = 2 do local c = 3 end end")?);
+        /// assert_eq!(visitor.names, vec!["b "]);
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub trait PathVisitor {
+            /// Visit the nodes of an [`Ast`](crate::ast::Ast)
+            fn visit_ast(&mut self, ast: &Ast) where Self: Sized {
+                PathTracker {
+                    visitor: self,
+                    path: Vec::new(),
+                }
+                .visit_ast(ast);
+            }
+
+            paste::item! {
+                $(
+                    #[allow(missing_docs)]
+                    fn $visit_name(&mut self, _node: &$ast_type, _path: &[$crate::visitors::NodePathSegment<NodeKind>]) { }
+                    #[allow(missing_docs)]
+                    fn [<$visit_name _end>](&mut self, _node: &$ast_type, _path: &[$crate::visitors::NodePathSegment<NodeKind>]) { }
+                )+
+
+                $(
+                    $(
+                        #[$meta]
+                        #[allow(missing_docs)]
+                        fn $meta_visit_name(&mut self, _node: &$meta_ast_type, _path: &[$crate::visitors::NodePathSegment<NodeKind>]) { }
+                        #[$meta]
+                        #[allow(missing_docs)]
+                        fn [<$meta_visit_name _end>](&mut self, _node: &$meta_ast_type, _path: &[$crate::visitors::NodePathSegment<NodeKind>]) { }
+                    )+
+                )+
+            }
+
+            $(
+                #[allow(missing_docs)]
+                fn $visit_token(&mut self, _token: &Token, _path: &[$crate::visitors::NodePathSegment<NodeKind>]) { }
+            )+
+
+            $(
+                $(
+                    #[$token_meta]
+                    #[allow(missing_docs)]
+                    fn $meta_visit_token(&mut self, _token: &Token, _path: &[$crate::visitors::NodePathSegment<NodeKind>]) { }
+                )+
+            )+
+        }
+
+        paste::item! {
+            /// The kind of an ancestor in a [`PathVisitor`] path, named after the [`Visitor`]
+            /// function that visits it, such as `NodeKind::VisitFunctionBody` for
+            /// `visit_function_body`
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #[non_exhaustive]
+            pub enum NodeKind {
+                $(
+                    #[allow(missing_docs)]
+                    [<$visit_name:camel>],
+                )+
+
+                $(
+                    $(
+                        #[$meta]
+                        #[allow(missing_docs)]
+                        [<$meta_visit_name:camel>],
+                    )+
+                )+
+            }
+        }
+
+        // Runs a PathVisitor as a Visitor, keeping track of the nodes that have been entered but not exited
+        struct PathTracker<'a, V> {
+            visitor: &'a mut V,
+            path: Vec<$crate::visitors::NodePathSegment<NodeKind>>,
+        }
+
+        impl<V> PathTracker<'_, V> {
+            // Token references are visited without a matching `_end` call, so they can't be ancestors
+            fn is_ancestor<N: 'static>() -> bool {
+                std::any::TypeId::of::<N>() != std::any::TypeId::of::<TokenReference>()
+            }
+
+            fn enter<N: $crate::node::Node<Symbol> + 'static>(&mut self, kind: NodeKind, node: &N) {
+                if Self::is_ancestor::<N>() {
+                    self.path.push($crate::visitors::NodePathSegment::new(kind, node.range()));
+                }
+            }
+
+            fn exit<N: 'static>(&mut self) {
+                if Self::is_ancestor::<N>() {
+                    self.path.pop();
+                }
+            }
+        }
+
+        impl<V: PathVisitor> Visitor for PathTracker<'_, V> {
+            paste::item! {
+                $(
+                    fn $visit_name(&mut self, node: &$ast_type) {
+                        self.visitor.$visit_name(node, &self.path);
+                        self.enter(NodeKind::[<$visit_name:camel>], node);
+                    }
+
+                    fn [<$visit_name _end>](&mut self, node: &$ast_type) {
+                        self.exit::<$ast_type>();
+                        self.visitor.[<$visit_name _end>](node, &self.path);
+                    }
+                )+
+
+                $(
+                    $(
+                        #[$meta]
+                        fn $meta_visit_name(&mut self, node: &$meta_ast_type) {
+                            self.visitor.$meta_visit_name(node, &self.path);
+                            self.enter(NodeKind::[<$meta_visit_name:camel>], node);
+                        }
+
+                        #[$meta]
+                        fn [<$meta_visit_name _end>](&mut self, node: &$meta_ast_type) {
+                            self.exit::<$meta_ast_type>();
+                            self.visitor.[<$meta_visit_name _end>](node, &self.path);
+                        }
+                    )+
+                )+
+            }
+
+            $(
+                fn $visit_token(&mut self, token: &Token) {
+                    self.visitor.$visit_token(token, &self.path);
+                }
+            )+
+
+            $(
+                $(
+                    #[$token_meta]
+                    fn $meta_visit_token(&mut self, token: &Token) {
+                        self.visitor.$meta_visit_token(token, &self.path);
+                    }
+                )+
+            )+
+        }
     };
 }
 
+/// An ancestor of a node visited by a `PathVisitor`, where `K` is the language's `NodeKind`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NodePathSegment<K> {
+    kind: K,
+    range: Option<(Position, Position)>,
+}
+
+impl<K: Copy> NodePathSegment<K> {
+    #[doc(hidden)]
+    pub fn new(kind: K, range: Option<(Position, Position)>) -> Self {
+        Self { kind, range }
+    }
+
+    /// The kind of node, such as `NodeKind::VisitFunctionBody` for nodes visited by
+    /// `visit_function_body`
+    pub fn kind(&self) -> K {
+        self.kind
+    }
+
+    /// The full range of the node, if it has both start and end positions
+    pub fn range(&self) -> Option<(Position, Position)> {
+        self.range
+    }
+}
+
 #[doc(hidden)]
 pub trait Visit<V> {
     fn visit(&self, visitor: &mut V);
@@ -160,6 +461,11 @@ where
     fn visit_mut(self, visitor: &mut V) -> Self;
 }
 
+#[doc(hidden)]
+pub trait TryVisit<V, B> {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B>;
+}
+
 impl<V, T: Visit<V>> Visit<V> for &T {
     fn visit(&self, visitor: &mut V) {
         (**self).visit(visitor);
@@ -172,6 +478,18 @@ impl<V, T: Visit<V>> Visit<V> for &mut T {
     }
 }
 
+impl<V, B, T: TryVisit<V, B>> TryVisit<V, B> for &T {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        (**self).try_visit(visitor)
+    }
+}
+
+impl<V, B, T: TryVisit<V, B>> TryVisit<V, B> for &mut T {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        (**self).try_visit(visitor)
+    }
+}
+
 impl<V, T: Visit<V>> Visit<V> for Vec<T> {
     fn visit(&self, visitor: &mut V) {
         for item in self {
@@ -188,6 +506,16 @@ impl<V, T: VisitMut<V>> VisitMut<V> for Vec<T> {
     }
 }
 
+impl<V, B, T: TryVisit<V, B>> TryVisit<V, B> for Vec<T> {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        for item in self {
+            item.try_visit(visitor)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
 impl<V, T: Visit<V>> Visit<V> for Option<T> {
     fn visit(&self, visitor: &mut V) {
         if let Some(item) = self {
@@ -202,6 +530,15 @@ impl<V, T: VisitMut<V>> VisitMut<V> for Option<T> {
     }
 }
 
+impl<V, B, T: TryVisit<V, B>> TryVisit<V, B> for Option<T> {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        match self {
+            Some(item) => item.try_visit(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+
 impl<V, A: Visit<V>, B: Visit<V>> Visit<V> for (A, B) {
     fn visit(&self, visitor: &mut V) {
         self.0.visit(visitor);
@@ -215,6 +552,13 @@ impl<V, A: VisitMut<V>, B: VisitMut<V>> VisitMut<V> for (A, B) {
     }
 }
 
+impl<V, Br, A: TryVisit<V, Br>, B: TryVisit<V, Br>> TryVisit<V, Br> for (A, B) {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<Br> {
+        self.0.try_visit(visitor)?;
+        self.1.try_visit(visitor)
+    }
+}
+
 impl<V, T: Visit<V>> Visit<V> for Box<T> {
     fn visit(&self, visitor: &mut V) {
         (**self).visit(visitor);
@@ -227,6 +571,12 @@ impl<V, T: VisitMut<V>> VisitMut<V> for Box<T> {
     }
 }
 
+impl<V, B, T: TryVisit<V, B>> TryVisit<V, B> for Box<T> {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<B> {
+        (**self).try_visit(visitor)
+    }
+}
+
 impl<S: crate::symbols::AnySymbol, V> Visit<V>
     for crate::tokenizer::TokenReference<S>
 {
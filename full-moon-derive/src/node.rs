@@ -200,7 +200,7 @@ impl StructGenerator for StructTokensGenerator {
             crate::node::Tokens {
                 items: vec![#(
                     #getters,
-                )*],
+                )*].into(),
             }
         }
     }
@@ -434,7 +434,7 @@ impl MatchEnumGenerator for EnumTokensGenerator {
                 crate::node::Tokens {
                     items: vec![#(
                         #getters,
-                    )*],
+                    )*].into(),
                 }
             }
         }
@@ -465,7 +465,7 @@ impl MatchEnumGenerator for EnumTokensGenerator {
                 crate::node::Tokens {
                     items: vec![#(
                         #getters,
-                    )*],
+                    )*].into(),
                 }
             }
         }
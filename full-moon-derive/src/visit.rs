@@ -95,6 +95,12 @@ impl DeriveGenerator for VisitGenerator {
         let input_ident = &input.ident;
         let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+        let mut try_generics = input.generics.clone();
+        try_generics
+            .params
+            .push(syn::parse_quote!(V: crate::visitors::TryVisitor));
+        let (try_impl_generics, _, try_where_clause) = try_generics.split_for_impl();
+
         let (visit_self, visit_self_end) = match search_hint("visit", &input.attrs) {
             Some(VisitHint::SkipVisitSelf) => (quote! {}, quote! {}),
             Some(VisitHint::VisitAs(visit_as)) => {
@@ -203,6 +209,43 @@ impl DeriveGenerator for VisitGenerator {
                     self
                 }
             }
+
+            #[allow(unused_macros)]
+            impl #try_impl_generics crate::visitors::TryVisit<V, V::Break> for #input_ident #ty_generics #try_where_clause {
+                fn try_visit(&self, visitor: &mut V) -> std::ops::ControlFlow<V::Break> {
+                    macro_rules! visit {
+                        ($visit_what: expr, $visitor: expr) => {
+                            $visit_what.try_visit($visitor)?;
+                        }
+                    }
+
+                    macro_rules! visit_self {
+                        ($name: ident) => {
+                            visitor.$name(self)?;
+                        }
+                    }
+
+                    macro_rules! set_self {
+                        ($value: expr) => {
+                            $value;
+                        }
+                    }
+
+                    // The fields are given as `field.visit(visitor)`, the same as for `Visit`
+                    macro_rules! if_visit {
+                        ({ $($used: ident.visit(visitor))* } else { $($unused: expr)* }) => {
+                            {
+                                $($used.try_visit(visitor)?;)*
+                            }
+                        }
+                    }
+
+                    #visit_self
+                    #tokens
+                    #visit_self_end
+                    std::ops::ControlFlow::Continue(())
+                }
+            }
         }
     }
 }
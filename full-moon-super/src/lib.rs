@@ -1,11 +1,13 @@
 mod ast;
 mod super_lexer;
 mod symbols;
+mod versions;
 mod visitors;
 
 use full_moon_common::language::Language;
-use super_lexer::SuperLexer;
+pub use super_lexer::SuperLexer;
 use symbols::Symbol;
+pub use versions::LuaVersion;
 
 struct SuperLanguage {}
 
@@ -15,4 +17,5 @@ pub struct SuperLua {
 
 impl Language<Symbol> for SuperLua {
     type Lex = SuperLexer;
+    type Version = LuaVersion;
 }
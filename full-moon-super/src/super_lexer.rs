@@ -1,6 +1,9 @@
-use crate::symbols::Symbol;
+use crate::{symbols::Symbol, LuaVersion};
 use full_moon_common::{
-    lexer::{is_identifier_start, Lexer, LexerResult, LexerSource, MultiLineBodyResult},
+    lexer::{
+        is_identifier_continue, is_identifier_start, Lexer, LexerResult, LexerSource,
+        MultiLineBodyResult,
+    },
     short_string::ShortString,
     tokenizer::{
         Position, StringLiteralQuoteType, Token, TokenReference, TokenType, TokenizerError,
@@ -8,6 +11,8 @@ use full_moon_common::{
     },
 };
 
+use std::collections::VecDeque;
+
 #[cfg(feature = "luau")]
 use super::{interpolated_strings, InterpolatedStringKind};
 
@@ -20,12 +25,30 @@ pub struct SuperLexer {
 
     next_token: Option<LexerResult<TokenReference<Symbol>>>,
     peek_token: Option<LexerResult<TokenReference<Symbol>>>,
+    // Tokens after `peek_token` that were processed by `peek_nth`
+    lookahead: VecDeque<LexerResult<TokenReference<Symbol>>>,
+    // Tokens from `lookahead` that are being returned one by one by `process_next`
+    lookahead_tokens: VecDeque<LexerResult<Token<Symbol>>>,
+
+    lua_version: LuaVersion,
 
     #[cfg(feature = "luau")]
     pub(crate) brace_stack: Vec<interpolated_strings::BraceType>,
 }
 
 impl SuperLexer {
+    /// Creates a new Lexer from the given source string, only producing the tokens available in
+    /// the given Lua version(s).
+    pub fn new_with_version(source: &str, lua_version: LuaVersion) -> Self {
+        let mut lexer = Self::new_lazy(source);
+        lexer.lua_version = lua_version;
+
+        lexer.next_token = lexer.process_first_with_trivia();
+        lexer.peek_token = lexer.process_next_with_trivia();
+
+        lexer
+    }
+
     fn create(
         &self,
         start_position: Position,
@@ -59,7 +82,7 @@ impl SuperLexer {
         let mut errors: Option<Vec<TokenizerError>> = None;
 
         let nontrivial_token = loop {
-            match self.process_next()? {
+            match self.process_next_source()? {
                 LexerResult::Ok(token) if token.token_type().is_trivia() => {
                     leading_trivia.push(token);
                 }
@@ -103,6 +126,23 @@ impl SuperLexer {
     }
 
     fn process_first_with_trivia(&mut self) -> Option<LexerResult<TokenReference<Symbol>>> {
+        let mut first_trivia = Vec::new();
+
+        // A UTF-8 byte order mark is only allowed at the very start of the code, where it is kept
+        // as whitespace so that printing the code reproduces it.
+        if self.source.current() == Some('\u{feff}') {
+            let start_position = self.source.position();
+            self.source.next();
+
+            first_trivia.push(Token {
+                token_type: TokenType::Whitespace {
+                    characters: ShortString::from("\u{feff}"),
+                },
+                start_position,
+                end_position: self.source.position(),
+            });
+        }
+
         if self.source.current() == Some('#') && self.source.peek() == Some('!') {
             let start_position = self.source.position();
             let mut line = "#!".to_string();
@@ -121,20 +161,27 @@ impl SuperLexer {
 
             let end_position = self.source.position();
 
-            let shebang = Token {
+            first_trivia.push(Token {
                 token_type: TokenType::Shebang { line: line.into() },
                 start_position,
                 end_position,
-            };
+            });
+        }
 
-            // rewrite todo: handle LexerResult better here
-            if let Some(LexerResult::Ok(mut token_reference)) = self.process_next_with_trivia() {
-                token_reference.leading_trivia.insert(0, shebang);
-                return Some(LexerResult::Ok(token_reference));
+        match self.process_next_with_trivia() {
+            Some(LexerResult::Ok(mut token_reference)) => {
+                token_reference.leading_trivia.splice(0..0, first_trivia);
+                Some(LexerResult::Ok(token_reference))
+            }
+
+            Some(LexerResult::Recovered(mut token_reference, errors)) => {
+                token_reference.leading_trivia.splice(0..0, first_trivia);
+                Some(LexerResult::Recovered(token_reference, errors))
             }
-        }
 
-        self.process_next_with_trivia()
+            // There is no token to attach the trivia to
+            other => other,
+        }
     }
 
     fn collect_trailing_trivia(&mut self) -> Vec<Token<Symbol>> {
@@ -144,7 +191,7 @@ impl SuperLexer {
             let sent_eof = self.sent_eof;
             let start_position = self.source.lexer_position;
 
-            match self.process_next() {
+            match self.process_next_source() {
                 Some(LexerResult::Ok(token)) if token.token_type().is_trivia() => {
                     // Take all trivia up to and including the newline character. If we see a newline character
                     // we should break once we have taken it in.
@@ -542,83 +589,10 @@ impl SuperLexer {
 
         MultiLineBodyResult::Ok { blocks, body }
     }
-}
-
-impl Lexer<Symbol> for SuperLexer {
-    /// Creates a new Lexer from the given source string and Lua version(s).
-    fn new(source: &str) -> Self {
-        let mut lexer = Self::new_lazy(source);
-
-        lexer.next_token = lexer.process_first_with_trivia();
-        lexer.peek_token = lexer.process_next_with_trivia();
-
-        lexer
-    }
-
-    /// Creates a new Lexer from the given source string and Lua version(s), but does not process
-    /// the first token.
-    fn new_lazy(source: &str) -> Self {
-        Self {
-            source: LexerSource::new(source),
-            sent_eof: false,
-
-            next_token: None,
-            peek_token: None,
-
-            #[cfg(feature = "luau")]
-            brace_stack: Vec::new(),
-        }
-    }
-
-    /// Returns the current token.
-    fn current(&self) -> Option<&LexerResult<TokenReference<Symbol>>> {
-        self.next_token.as_ref()
-    }
-
-    /// Returns the next token.
-    fn peek(&self) -> Option<&LexerResult<TokenReference<Symbol>>> {
-        self.peek_token.as_ref()
-    }
-
-    /// Consumes the current token and returns the next token.
-    fn consume(&mut self) -> Option<LexerResult<TokenReference<Symbol>>> {
-        let next = self.next_token.take()?;
-        self.next_token = self.peek_token.take();
-        self.peek_token = self.process_next_with_trivia();
-        Some(next)
-    }
-
-    /// Returns a vector of all tokens left in the source string.
-    fn collect(self) -> LexerResult<Vec<Token<Symbol>>> {
-        let mut tokens = Vec::new();
-        let mut lexer = self;
-        let mut errors = Vec::new();
-
-        while let Some(token_reference) = lexer.consume() {
-            let mut token_reference = match token_reference {
-                LexerResult::Ok(token_reference) => token_reference,
 
-                LexerResult::Recovered(token_reference, mut new_errors) => {
-                    errors.append(&mut new_errors);
-                    token_reference
-                }
-
-                LexerResult::Fatal(mut new_errors) => {
-                    errors.append(&mut new_errors);
-                    continue;
-                }
-            };
-
-            tokens.append(&mut token_reference.leading_trivia);
-            tokens.push(token_reference.token);
-            tokens.append(&mut token_reference.trailing_trivia);
-        }
-
-        LexerResult::new(tokens, errors)
-    }
-
-    /// Processes and returns the next token in the source string, ignoring trivia.
-    fn process_next(&mut self) -> Option<LexerResult<Token<Symbol>>> {
+    // Processes the next token straight from the source string, skipping over any tokens
+    // `peek_nth` has already processed
+    fn process_next_source(&mut self) -> Option<LexerResult<Token<Symbol>>> {
         let start_position = self.source.position();
 
         let Some(next) = self.source.next() else {
@@ -636,7 +610,7 @@ impl Lexer<Symbol> for SuperLexer {
                 identifier.push(initial);
 
                 while let Some(next) = self.source.current() {
-                    if is_identifier_start(next) || next.is_ascii_digit() {
+                    if is_identifier_continue(next) {
                         identifier.push(self.source.next().expect("peeked, but no next"));
                     } else {
                         break;
@@ -1254,3 +1228,127 @@ impl Lexer<Symbol> for SuperLexer {
         }
     }
 }
+
+impl Lexer<Symbol> for SuperLexer {
+    /// Creates a new Lexer from the given source string, using the default Lua version(s).
+    fn new(source: &str) -> Self {
+        Self::new_with_version(source, LuaVersion::new())
+    }
+
+    /// Creates a new Lexer from the given source string and Lua version(s), but does not process
+    /// the first token.
+    fn new_lazy(source: &str) -> Self {
+        Self {
+            source: LexerSource::new(source),
+            sent_eof: false,
+
+            next_token: None,
+            peek_token: None,
+            lookahead: VecDeque::new(),
+            lookahead_tokens: VecDeque::new(),
+
+            lua_version: LuaVersion::new(),
+
+            #[cfg(feature = "luau")]
+            brace_stack: Vec::new(),
+        }
+    }
+
+    /// Returns the current token.
+    fn current(&self) -> Option<&LexerResult<TokenReference<Symbol>>> {
+        self.next_token.as_ref()
+    }
+
+    /// Returns the next token.
+    fn peek(&self) -> Option<&LexerResult<TokenReference<Symbol>>> {
+        self.peek_token.as_ref()
+    }
+
+    /// Returns the token `n` tokens ahead of the current token.
+    fn peek_nth(&mut self, n: usize) -> Option<&LexerResult<TokenReference<Symbol>>> {
+        match n {
+            0 => self.next_token.as_ref(),
+            1 => self.peek_token.as_ref(),
+            _ => {
+                while self.lookahead.len() < n - 1 {
+                    let token = self.process_next_with_trivia()?;
+                    self.lookahead.push_back(token);
+                }
+
+                self.lookahead.get(n - 2)
+            }
+        }
+    }
+
+    /// Consumes the current token and returns the next token.
+    fn consume(&mut self) -> Option<LexerResult<TokenReference<Symbol>>> {
+        let next = self.next_token.take()?;
+        self.next_token = self.peek_token.take();
+        self.peek_token = match self.lookahead.pop_front() {
+            Some(token) => Some(token),
+            None => self.process_next_with_trivia(),
+        };
+        Some(next)
+    }
+
+    /// Returns a vector of all tokens left in the source string.
+    fn collect(self) -> LexerResult<Vec<Token<Symbol>>> {
+        let mut tokens = Vec::new();
+        let mut lexer = self;
+        let mut errors = Vec::new();
+
+        while let Some(token_reference) = lexer.consume() {
+            let mut token_reference = match token_reference {
+                LexerResult::Ok(token_reference) => token_reference,
+
+                LexerResult::Recovered(token_reference, mut new_errors) => {
+                    errors.append(&mut new_errors);
+                    token_reference
+                }
+
+                LexerResult::Fatal(mut new_errors) => {
+                    errors.append(&mut new_errors);
+                    continue;
+                }
+            };
+
+            tokens.append(&mut token_reference.leading_trivia);
+            tokens.push(token_reference.token);
+            tokens.append(&mut token_reference.trailing_trivia);
+        }
+
+        LexerResult::new(tokens, errors)
+    }
+
+    /// Processes and returns the next token in the source string, ignoring trivia.
+    fn process_next(&mut self) -> Option<LexerResult<Token<Symbol>>> {
+        // Tokens processed by `peek_nth` come before the rest of the source string
+        if let Some(token) = self.lookahead_tokens.pop_front() {
+            return Some(token);
+        }
+
+        let (token_reference, errors) = match self.lookahead.pop_front() {
+            Some(LexerResult::Ok(token_reference)) => (token_reference, Vec::new()),
+            Some(LexerResult::Recovered(token_reference, errors)) => (token_reference, errors),
+            Some(LexerResult::Fatal(errors)) => return Some(LexerResult::Fatal(errors)),
+            None => return self.process_next_source(),
+        };
+
+        self.lookahead_tokens.extend(
+            token_reference
+                .leading_trivia
+                .into_iter()
+                .map(LexerResult::Ok),
+        );
+        self.lookahead_tokens
+            .push_back(LexerResult::new(token_reference.token, errors));
+        self.lookahead_tokens.extend(
+            token_reference
+                .trailing_trivia
+                .into_iter()
+                .map(LexerResult::Ok),
+        );
+
+        self.process_next()
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{symbols::Symbol, SuperLua};
+use full_moon_common::tokenizer::{tokens, Token, TokenType};
+
+// Lua version is handled as a bitfield to support parsing as many languages as possible at once.
+// Any new language added does not necessarily need to (or should be) added to the default set.
+const VERSION_LUAU: u8 = 1 << 0;
+const VERSION_LUA52: u8 = 1 << 1;
+const VERSION_LUA53: u8 = 1 << 2;
+const VERSION_LUA54: u8 = 1 << 3;
+
+/// Represents the Lua version(s) to parse as.
+/// Lua 5.1 is always included.
+/// In order to get more Lua versions, you must include their respective features.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LuaVersion {
+    bitfield: u8,
+}
+
+impl LuaVersion {
+    /// Creates a new LuaVersion with the default features: Luau, Lua 5.2, Lua 5.3, and Lua 5.4.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new LuaVersion with only Lua 5.1.
+    pub fn lua51() -> Self {
+        Self { bitfield: 0 }
+    }
+
+    /// Creates a new LuaVersion with only Luau.
+    #[cfg(feature = "luau")]
+    pub fn luau() -> Self {
+        Self {
+            bitfield: VERSION_LUAU,
+        }
+    }
+
+    /// Adds Luau as a version to parse for.
+    #[cfg(feature = "luau")]
+    pub fn with_luau(self) -> Self {
+        Self {
+            bitfield: self.bitfield | VERSION_LUAU,
+        }
+    }
+
+    /// Returns true if Luau is enabled.
+    pub fn has_luau(self) -> bool {
+        cfg!(feature = "luau") && (self.bitfield & VERSION_LUAU != 0)
+    }
+
+    /// Creates a new LuaVersion with only Lua 5.2.
+    #[cfg(feature = "lua52")]
+    pub fn lua52() -> Self {
+        Self {
+            bitfield: VERSION_LUA52,
+        }
+    }
+
+    /// Adds Lua 5.2 as a version to parse for.
+    #[cfg(feature = "lua52")]
+    pub fn with_lua52(self) -> Self {
+        self | Self::lua52()
+    }
+
+    /// Returns true if Lua 5.2 is enabled.
+    pub fn has_lua52(self) -> bool {
+        cfg!(feature = "lua52") && (self.bitfield & VERSION_LUA52 != 0)
+    }
+
+    /// Creates a new LuaVersion with only Lua 5.3.
+    #[cfg(feature = "lua53")]
+    pub fn lua53() -> Self {
+        Self {
+            bitfield: VERSION_LUA52 | VERSION_LUA53,
+        }
+    }
+
+    /// Adds Lua 5.3 as a version to parse for.
+    #[cfg(feature = "lua53")]
+    pub fn with_lua53(self) -> Self {
+        self | Self::lua53()
+    }
+
+    /// Returns true if Lua 5.3 is enabled.
+    pub fn has_lua53(self) -> bool {
+        cfg!(feature = "lua53") && (self.bitfield & VERSION_LUA53 != 0)
+    }
+
+    /// Creates a new LuaVersion with only Lua 5.4.
+    #[cfg(feature = "lua54")]
+    pub fn lua54() -> Self {
+        Self {
+            bitfield: VERSION_LUA52 | VERSION_LUA53 | VERSION_LUA54,
+        }
+    }
+
+    /// Adds Lua 5.4 as a version to parse for.
+    #[cfg(feature = "lua54")]
+    pub fn with_lua54(self) -> Self {
+        self | Self::lua54()
+    }
+
+    /// Returns true if Lua 5.4 is enabled.
+    pub fn has_lua54(self) -> bool {
+        cfg!(feature = "lua54") && (self.bitfield & VERSION_LUA54 != 0)
+    }
+
+    /// Makes a best-effort guess of the Lua versions the given code is written for, based on the
+    /// features it uses. Every detected version is included in the result:
+    /// - `goto` and `::label::` imply Lua 5.2.
+    /// - Bitwise operators such as `&`, `|`, `~`, `<<`, and `>>`, as well as `//`, imply Lua 5.3.
+    /// - `<const>` and `<close>` attributes imply Lua 5.4.
+    /// - Compound assignments such as `+=`, interpolated strings, `->`, and type declarations imply Luau.
+    ///
+    /// Code that is ambiguous, or that can't be tokenized, is assumed to be Lua 5.1.
+    /// As Luau also uses `&` and `|` in types, Luau code using them will be detected as Lua 5.3 as well.
+    /// Versions whose features are not enabled are never returned, same as with [`LuaVersion::new`].
+    pub fn from_source(code: &str) -> Self {
+        let Ok(tokens) = tokens::<Symbol, SuperLua>(code) else {
+            return Self::lua51();
+        };
+
+        let tokens = tokens
+            .iter()
+            .map(Token::token_type)
+            .filter(|token_type| !token_type.is_trivia())
+            .collect::<Vec<_>>();
+
+        let mut bitfield = 0;
+
+        for (index, token_type) in tokens.iter().enumerate() {
+            let next = |offset: usize| tokens.get(index + offset).copied();
+
+            match token_type {
+                #[cfg(feature = "lua52")]
+                TokenType::Symbol {
+                    symbol: Symbol::Goto,
+                } => bitfield |= VERSION_LUA52,
+
+                #[cfg(any(feature = "luau", feature = "lua52"))]
+                TokenType::Symbol {
+                    symbol: Symbol::TwoColons,
+                } if is_identifier(next(1)) && is_symbol(next(2), Symbol::TwoColons) => {
+                    bitfield |= VERSION_LUA52
+                }
+
+                #[cfg(any(feature = "luau", feature = "lua53"))]
+                TokenType::Symbol {
+                    symbol: Symbol::Ampersand | Symbol::Pipe | Symbol::DoubleSlash,
+                } => bitfield |= VERSION_LUA52 | VERSION_LUA53,
+
+                #[cfg(feature = "lua53")]
+                TokenType::Symbol {
+                    symbol: Symbol::Tilde | Symbol::DoubleLessThan | Symbol::DoubleGreaterThan,
+                } => bitfield |= VERSION_LUA52 | VERSION_LUA53,
+
+                TokenType::Symbol {
+                    symbol: Symbol::LessThan,
+                } if is_symbol(next(2), Symbol::GreaterThan)
+                    && matches!(
+                        next(1),
+                        Some(TokenType::Identifier { identifier })
+                            if identifier.as_str() == "const" || identifier.as_str() == "close"
+                    ) =>
+                {
+                    bitfield |= VERSION_LUA52 | VERSION_LUA53 | VERSION_LUA54
+                }
+
+                #[cfg(feature = "luau")]
+                TokenType::Symbol {
+                    symbol:
+                        Symbol::PlusEqual
+                        | Symbol::MinusEqual
+                        | Symbol::StarEqual
+                        | Symbol::SlashEqual
+                        | Symbol::DoubleSlashEqual
+                        | Symbol::PercentEqual
+                        | Symbol::CaretEqual
+                        | Symbol::TwoDotsEqual
+                        | Symbol::ThinArrow,
+                } => bitfield |= VERSION_LUAU,
+
+                #[cfg(feature = "luau")]
+                TokenType::InterpolatedString { .. } => bitfield |= VERSION_LUAU,
+
+                TokenType::Identifier { identifier }
+                    if identifier.as_str() == "type"
+                        && is_identifier(next(1))
+                        && (is_symbol(next(2), Symbol::Equal)
+                            || is_symbol(next(2), Symbol::LessThan)) =>
+                {
+                    bitfield |= VERSION_LUAU
+                }
+
+                _ => {}
+            }
+        }
+
+        Self { bitfield }
+    }
+}
+
+fn is_identifier(token_type: Option<&TokenType<Symbol>>) -> bool {
+    matches!(token_type, Some(TokenType::Identifier { .. }))
+}
+
+fn is_symbol(token_type: Option<&TokenType<Symbol>>, expected: Symbol) -> bool {
+    matches!(token_type, Some(TokenType::Symbol { symbol }) if *symbol == expected)
+}
+
+impl Default for LuaVersion {
+    fn default() -> Self {
+        Self {
+            bitfield: VERSION_LUAU | VERSION_LUA52 | VERSION_LUA53 | VERSION_LUA54,
+        }
+    }
+}
+
+impl std::ops::BitOr for LuaVersion {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            bitfield: self.bitfield | rhs.bitfield,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lua51_sanity() {
+        assert!(!LuaVersion::lua51().has_lua52());
+        assert!(!LuaVersion::lua51().has_lua53());
+    }
+
+    #[test]
+    fn from_source_lua51() {
+        assert_eq!(LuaVersion::from_source("local x = 1"), LuaVersion::lua51());
+        assert_eq!(LuaVersion::from_source("local x = "), LuaVersion::lua51());
+        assert_eq!(
+            LuaVersion::from_source("local type = 1"),
+            LuaVersion::lua51()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lua52")]
+    fn from_source_lua52() {
+        assert!(LuaVersion::from_source("goto continue").has_lua52());
+        assert!(LuaVersion::from_source("::continue::").has_lua52());
+        assert!(!LuaVersion::from_source("goto continue").has_lua53());
+    }
+
+    #[test]
+    #[cfg(feature = "lua53")]
+    fn from_source_lua53() {
+        let version = LuaVersion::from_source("local x = 1 << 2");
+        assert!(version.has_lua52());
+        assert!(version.has_lua53());
+        assert!(!version.has_luau());
+    }
+
+    #[test]
+    #[cfg(feature = "lua54")]
+    fn from_source_lua54() {
+        assert!(LuaVersion::from_source("local x <const> = 1").has_lua54());
+    }
+
+    #[test]
+    #[cfg(all(feature = "luau", feature = "lua53"))]
+    fn from_source_union() {
+        let version = LuaVersion::from_source("x += 1 << 2\ntype Meters = number");
+        assert!(version.has_luau());
+        assert!(version.has_lua53());
+        assert!(!version.has_lua54());
+    }
+}
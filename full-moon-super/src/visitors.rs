@@ -2,8 +2,9 @@ use crate::symbols::Symbol;
 use full_moon_common::{
     create_visitor,
     tokenizer::{Token, TokenKind, TokenReference},
-    visitors::{Visit, VisitMut},
+    visitors::{TryVisit, Visit, VisitMut},
 };
+use std::ops::ControlFlow;
 
 impl Visit<dyn Visitor> for Token<Symbol> {
     fn visit(&self, visitor: &mut impl Visitor) {
@@ -78,6 +79,41 @@ impl VisitMut<dyn Visitor> for TokenReference<Symbol> {
     }
 }
 
+impl<V: TryVisitor> TryVisit<V, V::Break> for Token<Symbol> {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_token(self)?;
+
+        match self.token_kind() {
+            TokenKind::Eof => ControlFlow::Continue(()),
+            TokenKind::Identifier => visitor.visit_identifier(self),
+            TokenKind::MultiLineComment => visitor.visit_multi_line_comment(self),
+            TokenKind::Number => visitor.visit_number(self),
+            TokenKind::Shebang => ControlFlow::Continue(()),
+            TokenKind::SingleLineComment => visitor.visit_single_line_comment(self),
+            TokenKind::StringLiteral => visitor.visit_string_literal(self),
+            TokenKind::Symbol => visitor.visit_symbol(self),
+            TokenKind::Whitespace => visitor.visit_whitespace(self),
+
+            #[cfg(feature = "luau")]
+            TokenKind::InterpolatedString => visitor.visit_interpolated_string_segment(self),
+        }
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for TokenReference<Symbol> {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_token_reference(self)?;
+
+        if matches!(self.token().token_kind(), TokenKind::Eof) {
+            visitor.visit_eof(self)?;
+        }
+
+        self.leading_trivia.try_visit(visitor)?;
+        self.token.try_visit(visitor)?;
+        self.trailing_trivia.try_visit(visitor)
+    }
+}
+
 create_visitor!(ast: {
     visit_anonymous_call => FunctionArgs,
     visit_assignment => Assignment,
@@ -0,0 +1,99 @@
+use crate::{
+    ast::{BinOp, ElseIf, Expression, FunctionBody, GenericFor, If, NumericFor, Repeat, While},
+    visitors::{Visit, Visitor},
+};
+
+#[cfg(feature = "luau")]
+use crate::ast::types::{ElseIfExpression, IfExpression};
+
+// Counts the decision points of a function, skipping any functions nested inside of it
+#[derive(Default)]
+struct ComplexityVisitor {
+    decision_points: usize,
+    nested_functions: usize,
+}
+
+impl ComplexityVisitor {
+    fn add_decision_point(&mut self) {
+        if self.nested_functions == 0 {
+            self.decision_points += 1;
+        }
+    }
+}
+
+impl Visitor for ComplexityVisitor {
+    fn visit_function_body(&mut self, _: &FunctionBody) {
+        self.nested_functions += 1;
+    }
+
+    fn visit_function_body_end(&mut self, _: &FunctionBody) {
+        self.nested_functions -= 1;
+    }
+
+    fn visit_if(&mut self, _: &If) {
+        self.add_decision_point();
+    }
+
+    fn visit_else_if(&mut self, _: &ElseIf) {
+        self.add_decision_point();
+    }
+
+    fn visit_while(&mut self, _: &While) {
+        self.add_decision_point();
+    }
+
+    fn visit_repeat(&mut self, _: &Repeat) {
+        self.add_decision_point();
+    }
+
+    fn visit_numeric_for(&mut self, _: &NumericFor) {
+        self.add_decision_point();
+    }
+
+    fn visit_generic_for(&mut self, _: &GenericFor) {
+        self.add_decision_point();
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        if let Expression::BinaryOperator {
+            binop: BinOp::And(_) | BinOp::Or(_),
+            ..
+        } = expression
+        {
+            self.add_decision_point();
+        }
+    }
+
+    #[cfg(feature = "luau")]
+    fn visit_if_expression(&mut self, _: &IfExpression) {
+        self.add_decision_point();
+    }
+
+    #[cfg(feature = "luau")]
+    fn visit_else_if_expression(&mut self, _: &ElseIfExpression) {
+        self.add_decision_point();
+    }
+}
+
+impl FunctionBody {
+    /// The cyclomatic complexity of the function, a measure of how many paths can be taken
+    /// through it. Starts from 1, adding one for every `if` and `elseif`, loop, and `and` or
+    /// `or` operator. Functions defined inside of the function are not counted.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::Stmt;
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local function f(x) if x and x > 1 then return end end")?;
+    /// let Some(Stmt::LocalFunction(function)) = ast.nodes().stmts().next() else {
+    ///     unreachable!();
+    /// };
+    /// assert_eq!(function.body().cyclomatic_complexity(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cyclomatic_complexity(&self) -> usize {
+        let mut visitor = ComplexityVisitor::default();
+        self.block().visit(&mut visitor);
+        visitor.decision_points + 1
+    }
+}
@@ -1,7 +1,11 @@
 //! Contains the nodes necessary to parse [Lua 5.2](http://www.lua.org/manual/5.2/).
 //! Only usable when the "lua52" feature flag is enabled.
 
-use crate::tokenizer::TokenReference;
+use crate::{
+    ast::{Ast, Block, FunctionBody, Stmt},
+    tokenizer::TokenReference,
+    visitors::Visitor,
+};
 use derive_more::Display;
 use full_moon_common::{Node, Visit};
 
@@ -103,3 +107,106 @@ impl Label {
         }
     }
 }
+
+/// A goto statement paired with the label it jumps to, as returned by [`Ast::resolve_gotos`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GotoResolution {
+    goto: Goto,
+    label: Option<Label>,
+}
+
+impl GotoResolution {
+    /// The goto statement
+    pub fn goto(&self) -> &Goto {
+        &self.goto
+    }
+
+    /// The label the goto statement jumps to, or `None` if there is no visible label with its name
+    pub fn label(&self) -> Option<&Label> {
+        self.label.as_ref()
+    }
+
+    /// Whether the goto statement jumps to a label
+    pub fn is_resolved(&self) -> bool {
+        self.label.is_some()
+    }
+}
+
+impl Ast {
+    /// Pairs every goto statement in the code with the label it jumps to, in order.
+    /// Like in Lua, a label is visible in the entire block it is defined in, including nested blocks,
+    /// but not inside of nested functions. Gotos that jump into the scope of a local variable
+    /// are not detected.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("goto done; print(1); ::done:: goto missing")?;
+    /// let resolutions = ast.resolve_gotos();
+    /// assert!(resolutions[0].is_resolved());
+    /// assert!(!resolutions[1].is_resolved());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve_gotos(&self) -> Vec<GotoResolution> {
+        let mut resolver = GotoResolver::default();
+        resolver.visit_ast(self);
+        resolver.resolutions
+    }
+}
+
+enum LabelScope {
+    Block(Vec<Label>),
+    Function,
+}
+
+#[derive(Default)]
+struct GotoResolver {
+    scopes: Vec<LabelScope>,
+    resolutions: Vec<GotoResolution>,
+}
+
+impl Visitor for GotoResolver {
+    fn visit_block(&mut self, block: &Block) {
+        let labels = block
+            .stmts()
+            .filter_map(|stmt| match stmt {
+                Stmt::Label(label) => Some(label.clone()),
+                _ => None,
+            })
+            .collect();
+
+        self.scopes.push(LabelScope::Block(labels));
+    }
+
+    fn visit_block_end(&mut self, _: &Block) {
+        self.scopes.pop();
+    }
+
+    fn visit_function_body(&mut self, _: &FunctionBody) {
+        self.scopes.push(LabelScope::Function);
+    }
+
+    fn visit_function_body_end(&mut self, _: &FunctionBody) {
+        self.scopes.pop();
+    }
+
+    fn visit_goto(&mut self, goto: &Goto) {
+        let name = goto.label_name().token_type();
+        let label = self
+            .scopes
+            .iter()
+            .rev()
+            .map_while(|scope| match scope {
+                LabelScope::Block(labels) => Some(labels),
+                LabelScope::Function => None,
+            })
+            .flatten()
+            .find(|label| label.name().token_type() == name)
+            .cloned();
+
+        self.resolutions.push(GotoResolution {
+            goto: goto.clone(),
+            label,
+        });
+    }
+}
@@ -1,7 +1,10 @@
 //! Contains the nodes necessary to parse [Lua 5.4](http://www.lua.org/manual/5.4/).
 //! Only usable when the "lua54" feature flag is enabled.
 
-use crate::{ast::ContainedSpan, tokenizer::TokenReference};
+use crate::{
+    ast::ContainedSpan,
+    tokenizer::{TokenReference, TokenType},
+};
 use derive_more::Display;
 use full_moon_derive::{Node, Visit};
 
@@ -36,6 +39,26 @@ impl Attribute {
         &self.name
     }
 
+    /// The text of the name used for the attribute, such as `const` for `<const>`.
+    /// Returns an empty string if the name is not an identifier, which is never the case for
+    /// parsed code.
+    pub fn name_str(&self) -> &str {
+        match self.name.token_type() {
+            TokenType::Identifier { identifier } => identifier.as_str(),
+            _ => "",
+        }
+    }
+
+    /// Whether the attribute is `<const>`
+    pub fn is_const(&self) -> bool {
+        self.name_str() == "const"
+    }
+
+    /// Whether the attribute is `<close>`
+    pub fn is_close(&self) -> bool {
+        self.name_str() == "close"
+    }
+
     /// The angle brackets (`<` and `>`) surrounding the attribute
     pub fn brackets(&self) -> &ContainedSpan {
         &self.brackets
@@ -1,8 +1,10 @@
+mod complexity;
 mod parser_structs;
 #[macro_use]
 mod parser_util;
 mod parsers;
 // pub mod punctuated;
+mod shebang;
 pub mod span;
 mod update_positions;
 mod visitors;
@@ -23,9 +25,9 @@ use punctuated::{Pair, Punctuated};
 use span::ContainedSpan;
 
 pub use parser_structs::AstResult;
+pub(crate) use parser_structs::{parse_single_expression, parse_single_statement};
 
-mod versions;
-pub use versions::*;
+pub use full_moon_super::LuaVersion;
 
 #[cfg(feature = "luau")]
 pub mod types;
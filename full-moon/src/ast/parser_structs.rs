@@ -1,11 +1,15 @@
 use std::borrow::Cow;
 
 use crate::{
-    tokenizer::{Lexer, LexerResult, Symbol, TokenKind, TokenReference},
+    tokenizer::{Lexer, LexerResult, Position, Symbol, TokenKind, TokenReference},
     Language,
 };
 
-use super::{parsers::parse_block, Ast, Block};
+use super::{
+    parsers::{parse_block, parse_expression, parse_single_stmt},
+    Ast, Block, Expression, LuaVersion, Stmt,
+};
+use full_moon_super::{SuperLexer, SuperLua};
 
 
 
@@ -16,6 +20,7 @@ use super::{parsers::parse_block, Ast, Block};
 pub struct AstResult {
     ast: Ast,
     errors: Vec<crate::Error>,
+    lossless: bool,
 }
 
 impl AstResult {
@@ -40,11 +45,99 @@ impl AstResult {
         &self.errors
     }
 
-    pub(crate) fn parse_fallible<L: Language>(code: &str) -> Self {
-        const UNEXPECTED_TOKEN_ERROR: &str = "unexpected token, this needs to be a statement";
+    /// Returns all errors that occurred during parsing, but with cascading errors collapsed.
+    /// Consecutive errors from creating the AST that overlap, or that only have code the parser
+    /// skipped over between them, are merged into one error spanning all of them.
+    /// For example, `local x = = = =` will only have one error.
+    /// Errors caused by missing tokens and tokenizer errors are never merged.
+    /// Use [`AstResult::errors`] to get every error as it was produced.
+    pub fn deduplicated_errors(&self) -> Vec<crate::Error> {
+        let mut errors: Vec<crate::Error> = Vec::with_capacity(self.errors.len());
+
+        for error in &self.errors {
+            if let (Some(crate::Error::AstError(previous)), crate::Error::AstError(current)) =
+                (errors.last_mut(), error)
+            {
+                let (previous_start, previous_end) = previous.range();
+                let (current_start, current_end) = current.range();
 
+                if !previous.is_missing_token()
+                    && !current.is_missing_token()
+                    && self.is_same_region(previous_end, current_start)
+                {
+                    *previous = previous
+                        .clone()
+                        .with_range((previous_start, previous_end.max(current_end)));
+
+                    continue;
+                }
+            }
+
+            errors.push(error.clone());
+        }
+
+        errors
+    }
+
+    // Whether nothing the parser kept is between the end of one error and the start of the next
+    fn is_same_region(&self, end: Position, start: Position) -> bool {
+        start.bytes() <= end.bytes()
+            || !self.ast.tokens().any(|token| {
+                token.token().start_position().bytes() >= end.bytes()
+                    && token.token().end_position().bytes() <= start.bytes()
+                    && token.token_kind() != TokenKind::Eof
+            })
+    }
+
+    /// Returns true if any errors occurred during parsing.
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// Returns true if the [`Ast`](crate::ast::Ast) is exactly the same as the input code, meaning
+    /// no phantom tokens were inserted and printing it reproduces the code byte for byte.
+    /// This is always true when there are no errors, but can also be true for some errors,
+    /// such as tokenizer errors that were recovered from without changing the code.
+    /// When this is false, the printed code is not guaranteed to be valid Lua.
+    ///
+    /// ```rust
+    /// # use full_moon_super::SuperLua;
+    /// assert!(full_moon::parse_fallible::<SuperLua>("local x = 1").is_lossless());
+    /// assert!(!full_moon::parse_fallible::<SuperLua>("call(1, 2").is_lossless());
+    /// ```
+    pub fn is_lossless(&self) -> bool {
+        self.lossless
+    }
+
+    /// Returns the amount of errors that occurred during parsing.
+    pub fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// An iterator over only the errors that occurred while creating the AST,
+    /// skipping over any errors that occurred while tokenizing.
+    pub fn ast_errors(&self) -> impl Iterator<Item = &crate::ast::AstError> {
+        self.errors.iter().filter_map(|error| match error {
+            crate::Error::AstError(error) => Some(error),
+            crate::Error::TokenizerError(_) => None,
+        })
+    }
+
+    pub(crate) fn parse_fallible<L: Language>(code: &str) -> Self {
         let lexer: L::Lex = L::Lex::new(code);
-        let mut parser_state = ParserState::<L>::new(lexer);
+        Self::parse_with_state(code, ParserState::<L>::new(lexer))
+    }
+
+    pub(crate) fn parse_fallible_version(code: &str, lua_version: LuaVersion) -> Self {
+        let lexer = SuperLexer::new_with_version(code, lua_version);
+        Self::parse_with_state(
+            code,
+            ParserState::<SuperLua>::new(lexer).with_lua_version(lua_version),
+        )
+    }
+
+    fn parse_with_state<L: Language>(code: &str, mut parser_state: ParserState<L>) -> Self {
+        const UNEXPECTED_TOKEN_ERROR: &str = "unexpected token, this needs to be a statement";
 
         let mut block = match parse_block(&mut parser_state) {
             ParserResult::Value(block) => block,
@@ -123,9 +216,14 @@ impl AstResult {
 
         debug_assert_eq!(eof.token_kind(), TokenKind::Eof);
 
+        let ast = Ast { nodes: block, eof };
+        let lossless = parser_state.errors.is_empty()
+            || (!parser_state.synthesized_tokens() && crate::print(&ast) == code);
+
         Self {
-            ast: Ast { nodes: block, eof },
+            ast,
             errors: parser_state.errors,
+            lossless,
         }
     }
 
@@ -135,6 +233,65 @@ impl AstResult {
     }
 }
 
+// Parses code that is made up of exactly one node, such as one expression.
+// Unlike `AstResult::parse_fallible`, nothing is recovered, as any errors mean there is no node to give back.
+fn parse_fragment<L: Language, T>(
+    code: &str,
+    name: &'static str,
+    parser: impl FnOnce(&mut ParserState<L>) -> ParserResult<T>,
+) -> Result<T, Vec<crate::Error>> {
+    let lexer: L::Lex = L::Lex::new(code);
+    let mut parser_state = ParserState::<L>::new(lexer);
+
+    let value = match parser(&mut parser_state) {
+        ParserResult::Value(value) => Some(value),
+        ParserResult::LexerMoved => None,
+        ParserResult::NotFound => {
+            if let Ok(token) = parser_state.current() {
+                let token = token.clone();
+                parser_state.token_error(token, format!("expected {name}"));
+            }
+
+            None
+        }
+    };
+
+    loop {
+        match parser_state.current() {
+            Ok(token) if token.token_kind() == TokenKind::Eof => break,
+
+            Ok(token) => {
+                // Anything after a node that couldn't be parsed would only be a cascading error
+                if value.is_some() {
+                    let token = token.clone();
+                    parser_state.token_error(token, format!("unexpected token after {name}"));
+                }
+
+                break;
+            }
+
+            Err(()) => {
+                parser_state.consume();
+            }
+        }
+    }
+
+    match value {
+        Some(value) if parser_state.errors.is_empty() => Ok(value),
+        _ => Err(parser_state.errors),
+    }
+}
+
+pub(crate) fn parse_single_expression<L: Language>(
+    code: &str,
+) -> Result<Expression, Vec<crate::Error>> {
+    parse_fragment::<L, _>(code, "an expression", parse_expression)
+}
+
+pub(crate) fn parse_single_statement<L: Language>(code: &str) -> Result<Stmt, Vec<crate::Error>> {
+    parse_fragment::<L, _>(code, "a statement", parse_single_stmt)
+}
+
 impl From<AstResult> for Result<Ast, Vec<crate::Error>> {
     fn from(ast_result: AstResult) -> Self {
         if ast_result.errors.is_empty() {
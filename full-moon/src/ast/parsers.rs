@@ -114,6 +114,26 @@ enum StmtVariant {
     LastStmt(ast::LastStmt),
 }
 
+// Used for parsing a statement on its own, where last statements such as `continue` can't be used
+pub fn parse_single_stmt<L: Language>(state: &mut ParserState<L>) -> ParserResult<ast::Stmt> {
+    match parse_stmt(state) {
+        ParserResult::Value(StmtVariant::Stmt(stmt)) => ParserResult::Value(stmt),
+        ParserResult::Value(StmtVariant::LastStmt(last_stmt)) => {
+            let token = match last_stmt {
+                ast::LastStmt::Break(token) => token,
+                #[cfg(feature = "luau")]
+                ast::LastStmt::Continue(token) => token,
+                ast::LastStmt::Return(return_stmt) => return_stmt.token,
+            };
+
+            state.token_error(token, "expected a statement, not the end of a block");
+            ParserResult::LexerMoved
+        }
+        ParserResult::LexerMoved => ParserResult::LexerMoved,
+        ParserResult::NotFound => ParserResult::NotFound,
+    }
+}
+
 fn parse_stmt<L: Language>(state: &mut ParserState<L>) -> ParserResult<StmtVariant> {
     let Ok(current_token) = state.current() else {
         return ParserResult::NotFound;
@@ -1563,7 +1583,7 @@ fn parse_prefix_and_suffixes<L: Language>(
     ParserResult::Value((prefix, suffixes))
 }
 
-fn parse_expression<L: Language>(state: &mut ParserState<L>) -> ParserResult<Expression> {
+pub fn parse_expression<L: Language>(state: &mut ParserState<L>) -> ParserResult<Expression> {
     let primary_expression = match parse_primary_expression(state) {
         ParserResult::Value(expression) => expression,
         ParserResult::NotFound => return ParserResult::NotFound,
@@ -0,0 +1,63 @@
+use crate::{
+    ast::Ast,
+    tokenizer::{Token, TokenReference, TokenType},
+    visitors::VisitorMut,
+};
+
+#[derive(Default)]
+struct ShebangRemover {
+    visited_first_token: bool,
+}
+
+impl VisitorMut for ShebangRemover {
+    fn visit_token_reference(&mut self, token: TokenReference) -> TokenReference {
+        // The shebang can only be in the leading trivia of the very first token
+        if std::mem::replace(&mut self.visited_first_token, true) {
+            return token;
+        }
+
+        let mut leading_trivia = token.leading_trivia().cloned().collect::<Vec<Token>>();
+
+        // This is usually the first trivia, but it comes after the byte order mark if there is one
+        let Some(shebang_index) = leading_trivia
+            .iter()
+            .position(|trivia| matches!(trivia.token_type(), TokenType::Shebang { .. }))
+        else {
+            return token;
+        };
+
+        leading_trivia.remove(shebang_index);
+
+        // The line ending of the shebang is its own token, which would otherwise be left as a blank line
+        if matches!(
+            leading_trivia.get(shebang_index).map(Token::token_type),
+            Some(TokenType::Whitespace { characters }) if characters.as_str() == "\n"
+        ) {
+            leading_trivia.remove(shebang_index);
+        }
+
+        token.with_leading_trivia(leading_trivia)
+    }
+}
+
+impl Ast {
+    /// Returns a new Ast without the shebang line at the start of the code, such as `#!/usr/bin/env lua`,
+    /// along with its line ending. Returns the Ast unchanged if there is no shebang.
+    /// Positions are not updated, use [`Ast::update_positions`] if you need them afterwards.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("#!/usr/bin/env lua\nprint(1)")?.without_shebang();
+    /// assert!(ast.shebang().is_none());
+    /// assert_eq!(full_moon::print(&ast), "print(1)");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn without_shebang(self) -> Self {
+        if self.shebang().is_none() {
+            return self;
+        }
+
+        ShebangRemover::default().visit_ast(self)
+    }
+}
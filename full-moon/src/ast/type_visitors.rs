@@ -1,7 +1,8 @@
 // Implementations of Visit and VisitMut that are not able to be automatically derived yet.
 // Ideally everything would be derived.
 use super::*;
-use crate::visitors::{Visit, VisitMut, Visitor, VisitorMut};
+use crate::visitors::{TryVisit, TryVisitor, Visit, VisitMut, Visitor, VisitorMut};
+use std::ops::ControlFlow;
 
 // The following have `ContainedSpan`, which when automatically derived will visit the tokens containing
 // before they visit what they're actually containing.
@@ -278,6 +279,115 @@ impl VisitMut for TypeInfo {
     }
 }
 
+impl<V: TryVisitor> TryVisit<V, V::Break> for TypeInfo {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_type_info(self)?;
+        match self {
+            TypeInfo::Array { braces, type_info } => {
+                braces.tokens.0.try_visit(visitor)?;
+                type_info.try_visit(visitor)?;
+                braces.tokens.1.try_visit(visitor)?;
+            }
+            TypeInfo::Basic(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+            TypeInfo::String(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+            TypeInfo::Boolean(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+            TypeInfo::Callback {
+                generics,
+                parentheses,
+                arguments,
+                arrow,
+                return_type,
+            } => {
+                generics.try_visit(visitor)?;
+                parentheses.tokens.0.try_visit(visitor)?;
+                arguments.try_visit(visitor)?;
+                parentheses.tokens.1.try_visit(visitor)?;
+                arrow.try_visit(visitor)?;
+                return_type.try_visit(visitor)?;
+            }
+            TypeInfo::Generic {
+                base,
+                arrows,
+                generics,
+            } => {
+                base.try_visit(visitor)?;
+                arrows.tokens.0.try_visit(visitor)?;
+                generics.try_visit(visitor)?;
+                arrows.tokens.1.try_visit(visitor)?;
+            }
+            TypeInfo::GenericPack { name, ellipse } => {
+                name.try_visit(visitor)?;
+                ellipse.try_visit(visitor)?;
+            }
+            TypeInfo::Module {
+                module,
+                punctuation,
+                type_info,
+            } => {
+                module.try_visit(visitor)?;
+                punctuation.try_visit(visitor)?;
+                type_info.try_visit(visitor)?;
+            }
+            TypeInfo::Optional {
+                base,
+                question_mark,
+            } => {
+                base.try_visit(visitor)?;
+                question_mark.try_visit(visitor)?;
+            }
+            TypeInfo::Table { braces, fields } => {
+                braces.tokens.0.try_visit(visitor)?;
+                fields.try_visit(visitor)?;
+                braces.tokens.1.try_visit(visitor)?;
+            }
+            TypeInfo::Typeof {
+                typeof_token,
+                parentheses,
+                inner,
+            } => {
+                typeof_token.try_visit(visitor)?;
+                parentheses.tokens.0.try_visit(visitor)?;
+                inner.try_visit(visitor)?;
+                parentheses.tokens.1.try_visit(visitor)?;
+            }
+            TypeInfo::Tuple { parentheses, types } => {
+                parentheses.tokens.0.try_visit(visitor)?;
+                types.try_visit(visitor)?;
+                parentheses.tokens.1.try_visit(visitor)?;
+            }
+            TypeInfo::Union { left, pipe, right } => {
+                left.try_visit(visitor)?;
+                pipe.try_visit(visitor)?;
+                right.try_visit(visitor)?;
+            }
+            TypeInfo::Intersection {
+                left,
+                ampersand,
+                right,
+            } => {
+                left.try_visit(visitor)?;
+                ampersand.try_visit(visitor)?;
+                right.try_visit(visitor)?;
+            }
+            TypeInfo::Variadic { ellipse, type_info } => {
+                ellipse.try_visit(visitor)?;
+                type_info.try_visit(visitor)?;
+            }
+            TypeInfo::VariadicPack { ellipse, name } => {
+                ellipse.try_visit(visitor)?;
+                name.try_visit(visitor)?;
+            }
+        };
+        visitor.visit_type_info_end(self)
+    }
+}
+
 impl Visit for IndexedTypeInfo {
     fn visit<V: Visitor>(&self, visitor: &mut V) {
         visitor.visit_indexed_type_info(self);
@@ -328,6 +438,28 @@ impl VisitMut for IndexedTypeInfo {
     }
 }
 
+impl<V: TryVisitor> TryVisit<V, V::Break> for IndexedTypeInfo {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_indexed_type_info(self)?;
+        match self {
+            IndexedTypeInfo::Basic(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+            IndexedTypeInfo::Generic {
+                base,
+                arrows,
+                generics,
+            } => {
+                base.try_visit(visitor)?;
+                arrows.tokens.0.try_visit(visitor)?;
+                generics.try_visit(visitor)?;
+                arrows.tokens.1.try_visit(visitor)?;
+            }
+        };
+        visitor.visit_indexed_type_info_end(self)
+    }
+}
+
 impl Visit for TypeFieldKey {
     fn visit<V: Visitor>(&self, visitor: &mut V) {
         visitor.visit_type_field_key(self);
@@ -365,3 +497,20 @@ impl VisitMut for TypeFieldKey {
         self
     }
 }
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for TypeFieldKey {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_type_field_key(self)?;
+        match self {
+            TypeFieldKey::Name(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+            TypeFieldKey::IndexSignature { brackets, inner } => {
+                brackets.tokens.0.try_visit(visitor)?;
+                inner.try_visit(visitor)?;
+                brackets.tokens.1.try_visit(visitor)?;
+            }
+        };
+        visitor.visit_type_field_key_end(self)
+    }
+}
@@ -3,11 +3,13 @@
 //! It will be renamed to "luau" in the future.
 use super::{punctuated::Punctuated, span::ContainedSpan, *};
 use crate::{
+    tokenizer::InterpolatedStringKind,
     util::display_option,
-    visitors::{Visit, VisitMut},
+    visitors::{TryVisit, TryVisitor, Visit, VisitMut},
     ShortString,
 };
 use derive_more::Display;
+use std::ops::ControlFlow;
 
 /// Any type, such as `string`, `boolean?`, `number | boolean`, etc.
 #[derive(Clone, Debug, Display, PartialEq, Node)]
@@ -183,6 +185,35 @@ pub enum TypeInfo {
     },
 }
 
+impl TypeInfo {
+    /// Whether the type is optional, such as `string?`
+    pub fn is_optional(&self) -> bool {
+        matches!(self, TypeInfo::Optional { .. })
+    }
+
+    /// The type made optional, such as `string` for `string?`.
+    /// Returns `None` if the type is not optional.
+    pub fn unwrap_optional(&self) -> Option<&TypeInfo> {
+        match self {
+            TypeInfo::Optional { base, .. } => Some(base),
+            _ => None,
+        }
+    }
+
+    /// The name of a plain named type, such as `string` or `Foo`.
+    /// Returns `None` for any other type, including generic types such as `Array<string>`,
+    /// types from other modules such as `Module.Foo`, and `nil`, which is a symbol rather than a name.
+    pub fn as_simple_name(&self) -> Option<&str> {
+        match self {
+            TypeInfo::Basic(token) => match token.token_type() {
+                TokenType::Identifier { identifier } => Some(identifier.as_str()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 /// A subset of TypeInfo that consists of items which can only be used as an index, such as `Foo` and `Foo<Bar>`,
 #[derive(Clone, Debug, Display, PartialEq, Node)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
@@ -766,6 +797,56 @@ impl CompoundAssignment {
         &self.rhs
     }
 
+    /// Rewrites the compound assignment into an [`Assignment`] usable outside of Luau, such as
+    /// `x = x + 1` for `x += 1`. The operator keeps the trivia of the compound operator, and the
+    /// value is wrapped in parentheses when needed to keep the order of operations, such as
+    /// `x = x * (1 + 2)` for `x *= 1 + 2`.
+    ///
+    /// The variable is cloned into both sides of the assignment, so any expressions inside of it
+    /// are evaluated twice, such as `f()` in `t[f()] += 1`.
+    pub fn desugar(&self) -> Assignment {
+        let token = self.compound_operator.token();
+        let operator_token = |symbol| token.with_token_type(TokenType::Symbol { symbol });
+
+        let bin_op = match self.compound_operator {
+            CompoundOp::PlusEqual(_) => BinOp::Plus(operator_token(Symbol::Plus)),
+            CompoundOp::MinusEqual(_) => BinOp::Minus(operator_token(Symbol::Minus)),
+            CompoundOp::StarEqual(_) => BinOp::Star(operator_token(Symbol::Star)),
+            CompoundOp::SlashEqual(_) => BinOp::Slash(operator_token(Symbol::Slash)),
+            CompoundOp::DoubleSlashEqual(_) => {
+                BinOp::DoubleSlash(operator_token(Symbol::DoubleSlash))
+            }
+            CompoundOp::PercentEqual(_) => BinOp::Percent(operator_token(Symbol::Percent)),
+            CompoundOp::CaretEqual(_) => BinOp::Caret(operator_token(Symbol::Caret)),
+            CompoundOp::TwoDotsEqual(_) => BinOp::TwoDots(operator_token(Symbol::TwoDots)),
+        };
+
+        let rhs = match &self.rhs {
+            Expression::BinaryOperator { binop, .. } if needs_parentheses(&bin_op, binop) => {
+                Expression::Parentheses {
+                    contained: ContainedSpan::new(
+                        TokenReference::symbol("(").unwrap(),
+                        TokenReference::symbol(")").unwrap(),
+                    ),
+                    expression: Box::new(self.rhs.clone()),
+                }
+            }
+            rhs => rhs.clone(),
+        };
+
+        let mut var_list = Punctuated::new();
+        var_list.push(Pair::End(self.lhs.clone()));
+
+        let mut expr_list = Punctuated::new();
+        expr_list.push(Pair::End(Expression::BinaryOperator {
+            lhs: Box::new(Expression::Var(self.lhs.clone())),
+            binop: bin_op,
+            rhs: Box::new(rhs),
+        }));
+
+        Assignment::new(var_list, expr_list).with_equal_token(operator_token(Symbol::Equal))
+    }
+
     /// Returns a new CompoundAssignment with the given variable being assigned to
     pub fn with_lhs(self, lhs: Var) -> Self {
         Self { lhs, ..self }
@@ -785,6 +866,16 @@ impl CompoundAssignment {
     }
 }
 
+// Whether an operand on the right of an operator must be parenthesized to keep its meaning
+fn needs_parentheses(operator: &BinOp, rhs_operator: &BinOp) -> bool {
+    let precedence = BinOp::precedence_of_token(operator.token());
+    let rhs_precedence = BinOp::precedence_of_token(rhs_operator.token());
+
+    rhs_precedence < precedence
+        || (rhs_precedence == precedence
+            && !matches!(operator, BinOp::Caret(_) | BinOp::TwoDots(_)))
+}
+
 /// An if statement
 #[derive(Clone, Debug, Display, PartialEq, Node, Visit)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
@@ -1001,6 +1092,51 @@ impl InterpolatedString {
         }
     }
 
+    /// Creates a new InterpolatedString out of literal text and expressions, such as the ones
+    /// returned by [`InterpolatedString::parts`]. Consecutive literals are joined together, and
+    /// parts without an expression, such as a single literal, create a simple string with no segments.
+    /// Literals are written as is, so any backticks or braces in them must already be escaped.
+    ///
+    /// ```rust
+    /// # use full_moon::ast::{types::{InterpolatedPart, InterpolatedString}, Expression};
+    /// let string = InterpolatedString::from_parts(vec![
+    ///     InterpolatedPart::Literal("count: ".to_owned()),
+    ///     InterpolatedPart::Expression(Expression::number("3")),
+    ///     InterpolatedPart::Literal("!".to_owned()),
+    /// ]);
+    /// assert_eq!(string.to_string(), "`count: {3}!`");
+    /// ```
+    pub fn from_parts(parts: Vec<InterpolatedPart>) -> Self {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+
+        for part in parts {
+            match part {
+                InterpolatedPart::Literal(text) => literal.push_str(&text),
+                InterpolatedPart::Expression(expression) => {
+                    let kind = if segments.is_empty() {
+                        InterpolatedStringKind::Begin
+                    } else {
+                        InterpolatedStringKind::Middle
+                    };
+
+                    segments.push(InterpolatedStringSegment {
+                        literal: interpolated_token(std::mem::take(&mut literal), kind),
+                        expression,
+                    });
+                }
+            }
+        }
+
+        let kind = if segments.is_empty() {
+            InterpolatedStringKind::Simple
+        } else {
+            InterpolatedStringKind::End
+        };
+
+        Self::new(segments, interpolated_token(literal, kind))
+    }
+
     /// The segments of the interpolated string
     pub fn segments(&self) -> impl Iterator<Item = &InterpolatedStringSegment> {
         self.segments.iter()
@@ -1011,6 +1147,28 @@ impl InterpolatedString {
         &self.last_string
     }
 
+    /// The literal text and embedded expressions of the string, in order, always alternating and
+    /// starting and ending with a literal. `` `a {x} b {y}` `` yields `"a "`, `x`, `" b "`, `y`,
+    /// and an empty `""`. Literals are the text as written, with escape sequences left untouched.
+    /// The parts are cloned out of the string, so they can be passed to
+    /// [`InterpolatedString::from_parts`] to build a new one.
+    pub fn parts(&self) -> impl Iterator<Item = InterpolatedPart> + '_ {
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                interpolated_literal(&segment.literal)
+                    .map(|literal| InterpolatedPart::Literal(literal.to_string()))
+                    .into_iter()
+                    .chain(std::iter::once(InterpolatedPart::Expression(
+                        segment.expression.clone(),
+                    )))
+            })
+            .chain(
+                interpolated_literal(&self.last_string)
+                    .map(|literal| InterpolatedPart::Literal(literal.to_string())),
+            )
+    }
+
     /// Returns just the expressions
     pub fn expressions(&self) -> impl Iterator<Item = &Expression> {
         ExpressionsIterator {
@@ -1062,6 +1220,42 @@ impl VisitMut for InterpolatedStringSegment {
     }
 }
 
+impl<V: TryVisitor> TryVisit<V, V::Break> for InterpolatedStringSegment {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        self.literal.try_visit(visitor)?;
+        self.expression.try_visit(visitor)
+    }
+}
+
+/// A part of an interpolated string, as returned by [`InterpolatedString::parts`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterpolatedPart {
+    /// Literal text, such as `hello, ` in `` `hello, {name}` ``, without backticks or braces
+    Literal(String),
+    /// An embedded expression, such as `name` in `` `hello, {name}` ``
+    Expression(Expression),
+}
+
+// The literal text of a token, which is always an interpolated string for parsed code
+fn interpolated_literal(token: &TokenReference) -> Option<&ShortString> {
+    match token.token_type() {
+        TokenType::InterpolatedString { literal, .. } => Some(literal),
+        _ => None,
+    }
+}
+
+// Creates the token for a literal of an interpolated string, with no trivia
+fn interpolated_token(literal: String, kind: InterpolatedStringKind) -> TokenReference {
+    TokenReference::new(
+        Vec::new(),
+        Token::new(TokenType::InterpolatedString {
+            literal: literal.into(),
+            kind,
+        }),
+        Vec::new(),
+    )
+}
+
 struct ExpressionsIterator<'a> {
     segments: &'a [InterpolatedStringSegment],
     index: usize,
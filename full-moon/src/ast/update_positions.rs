@@ -72,12 +72,20 @@ impl Ast {
     /// Will update the positions of all the tokens in the tree
     /// Necessary if you are both mutating the tree and need the positions of the tokens
     pub fn update_positions(self) -> Self {
+        self.update_positions_from(Position {
+            bytes: 0,
+            character: 1,
+            line: 1,
+        })
+    }
+
+    /// Will update the positions of all the tokens in the tree, as if the code started at the given position
+    /// rather than at the start of a file. Useful when the code is embedded into a larger document.
+    /// Like with [`Ast::update_positions`], phantom tokens created by [`parse_fallible`](crate::parse_fallible)
+    /// have null positions before this is called, and will be given positions as if they were in the code.
+    pub fn update_positions_from(self, start: Position) -> Self {
         let mut rewriter = UpdatePositionsRewriter {
-            start_position: Position {
-                bytes: 0,
-                character: 1,
-                line: 1,
-            },
+            start_position: start,
 
             ..Default::default()
         };
@@ -88,7 +96,7 @@ impl Ast {
 
 #[cfg(test)]
 mod tests {
-    use crate::{node::Node, parse};
+    use crate::{node::Node, parse, tokenizer::Position};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -106,4 +114,29 @@ mod tests {
                 .collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn test_update_positions_from() {
+        let start = Position {
+            bytes: 100,
+            line: 5,
+            character: 3,
+        };
+
+        let ast = parse("local foo = 1\nlocal bar = 2")
+            .unwrap()
+            .update_positions_from(start);
+
+        let positions: Vec<_> = ast
+            .tokens()
+            .map(|token| {
+                let position = token.token().start_position();
+                (position.bytes(), position.line(), position.character())
+            })
+            .collect();
+
+        assert_eq!(positions[0], (100, 5, 3));
+        assert_eq!(positions[1], (106, 5, 9));
+        assert_eq!(positions[4], (114, 6, 1));
+    }
 }
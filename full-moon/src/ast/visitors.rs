@@ -1,7 +1,8 @@
 // Implementations of Visit and VisitMut that are not able to be automatically derived yet.
 // Ideally everything would be derived.
 use super::*;
-use crate::visitors::{Visit, VisitMut, Visitor, VisitorMut};
+use crate::visitors::{TryVisit, TryVisitor, Visit, VisitMut, Visitor, VisitorMut};
+use std::ops::ControlFlow;
 
 // The following have `ContainedSpan`, which when automatically derived will visit the tokens containing
 // before they visit what they're actually containing.
@@ -673,3 +674,286 @@ impl VisitMut for NumericFor {
         self
     }
 }
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for Field {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_field(self)?;
+        match self {
+            Field::ExpressionKey {
+                brackets,
+                key,
+                equal,
+                value,
+            } => {
+                brackets.tokens.0.try_visit(visitor)?;
+                key.try_visit(visitor)?;
+                brackets.tokens.1.try_visit(visitor)?;
+                equal.try_visit(visitor)?;
+                value.try_visit(visitor)?;
+            }
+
+            Field::NameKey { key, equal, value } => {
+                key.try_visit(visitor)?;
+                equal.try_visit(visitor)?;
+                value.try_visit(visitor)?;
+            }
+
+            Field::NoKey(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+        };
+
+        visitor.visit_field_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for Expression {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_expression(self)?;
+        match self {
+            Expression::BinaryOperator { lhs, binop, rhs } => {
+                lhs.try_visit(visitor)?;
+                binop.try_visit(visitor)?;
+                rhs.try_visit(visitor)?;
+            }
+
+            Expression::Parentheses {
+                contained,
+                expression,
+            } => {
+                contained.tokens.0.try_visit(visitor)?;
+                expression.try_visit(visitor)?;
+                contained.tokens.1.try_visit(visitor)?;
+            }
+
+            Expression::UnaryOperator { unop, expression } => {
+                unop.try_visit(visitor)?;
+                expression.try_visit(visitor)?;
+            }
+
+            Expression::Function((function_token, function_body)) => {
+                function_token.try_visit(visitor)?;
+                function_body.try_visit(visitor)?;
+            }
+
+            Expression::FunctionCall(function_call) => {
+                function_call.try_visit(visitor)?;
+            }
+
+            #[cfg(feature = "luau")]
+            Expression::IfExpression(if_expression) => {
+                if_expression.try_visit(visitor)?;
+            }
+
+            #[cfg(feature = "luau")]
+            Expression::InterpolatedString(interpolated_string) => {
+                interpolated_string.try_visit(visitor)?;
+            }
+
+            Expression::TableConstructor(table_constructor) => {
+                table_constructor.try_visit(visitor)?;
+            }
+
+            #[cfg(feature = "luau")]
+            Expression::TypeAssertion {
+                expression,
+                type_assertion,
+            } => {
+                expression.try_visit(visitor)?;
+                type_assertion.try_visit(visitor)?;
+            }
+
+            Expression::Number(token) | Expression::String(token) | Expression::Symbol(token) => {
+                token.try_visit(visitor)?;
+            }
+
+            Expression::Var(var) => {
+                var.try_visit(visitor)?;
+            }
+        };
+
+        visitor.visit_expression_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for Index {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_index(self)?;
+        match self {
+            Index::Brackets {
+                brackets,
+                expression,
+            } => {
+                brackets.tokens.0.try_visit(visitor)?;
+                expression.try_visit(visitor)?;
+                brackets.tokens.1.try_visit(visitor)?;
+            }
+            Index::Dot { dot, name } => {
+                dot.try_visit(visitor)?;
+                name.try_visit(visitor)?;
+            }
+        };
+
+        visitor.visit_index_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for FunctionArgs {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_function_args(self)?;
+        match self {
+            FunctionArgs::Parentheses {
+                parentheses,
+                arguments,
+            } => {
+                parentheses.tokens.0.try_visit(visitor)?;
+                arguments.try_visit(visitor)?;
+                parentheses.tokens.1.try_visit(visitor)?;
+            }
+            FunctionArgs::String(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+            FunctionArgs::TableConstructor(__self_0) => {
+                __self_0.try_visit(visitor)?;
+            }
+        };
+
+        visitor.visit_function_args_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for FunctionBody {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_function_body(self)?;
+
+        #[cfg(feature = "luau")]
+        self.generics.try_visit(visitor)?;
+
+        self.parameters_parentheses.tokens.0.try_visit(visitor)?;
+
+        let mut type_specifiers;
+
+        #[cfg(feature = "luau")]
+        {
+            type_specifiers = self.type_specifiers();
+        }
+
+        #[cfg(not(feature = "luau"))]
+        {
+            // TODO: Option<!>, and implement Visit for !
+            type_specifiers = std::iter::repeat::<Option<Self>>(None);
+        }
+
+        for parameter in &self.parameters {
+            parameter.try_visit(visitor)?;
+            type_specifiers.next().try_visit(visitor)?;
+        }
+
+        self.parameters_parentheses.tokens.1.try_visit(visitor)?;
+
+        #[cfg(feature = "luau")]
+        self.return_type.try_visit(visitor)?;
+
+        self.block.try_visit(visitor)?;
+        self.end_token.try_visit(visitor)?;
+        visitor.visit_function_body_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for LocalAssignment {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_local_assignment(self)?;
+        self.local_token.try_visit(visitor)?;
+
+        let mut attributes;
+        let mut type_specifiers;
+
+        #[cfg(feature = "lua54")]
+        {
+            attributes = self.attributes();
+        }
+
+        #[cfg(not(feature = "lua54"))]
+        {
+            // TODO: Option<!>, and implement Visit for !
+            attributes = std::iter::repeat::<Option<Self>>(None);
+        }
+
+        #[cfg(feature = "luau")]
+        {
+            type_specifiers = self.type_specifiers();
+        }
+
+        #[cfg(not(feature = "luau"))]
+        {
+            // TODO: Option<!>, and implement Visit for !
+            type_specifiers = std::iter::repeat::<Option<Self>>(None);
+        }
+
+        for name in &self.name_list {
+            name.try_visit(visitor)?;
+            attributes.next().try_visit(visitor)?;
+            type_specifiers.next().try_visit(visitor)?;
+        }
+
+        self.equal_token.try_visit(visitor)?;
+        self.expr_list.try_visit(visitor)?;
+        visitor.visit_local_assignment_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for GenericFor {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_generic_for(self)?;
+        self.for_token.try_visit(visitor)?;
+
+        let mut type_specifiers;
+
+        #[cfg(feature = "luau")]
+        {
+            type_specifiers = self.type_specifiers();
+        }
+
+        #[cfg(not(feature = "luau"))]
+        {
+            // TODO: Option<!>, and implement Visit for !
+            type_specifiers = std::iter::repeat::<Option<Self>>(None);
+        }
+
+        for name in &self.names {
+            name.try_visit(visitor)?;
+            type_specifiers.next().try_visit(visitor)?;
+        }
+
+        self.in_token.try_visit(visitor)?;
+        self.expr_list.try_visit(visitor)?;
+        self.do_token.try_visit(visitor)?;
+        self.block.try_visit(visitor)?;
+        self.end_token.try_visit(visitor)?;
+
+        visitor.visit_generic_for_end(self)
+    }
+}
+
+impl<V: TryVisitor> TryVisit<V, V::Break> for NumericFor {
+    fn try_visit(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        visitor.visit_numeric_for(self)?;
+        self.for_token.try_visit(visitor)?;
+        self.index_variable.try_visit(visitor)?;
+
+        #[cfg(feature = "luau")]
+        self.type_specifier.try_visit(visitor)?;
+
+        self.equal_token.try_visit(visitor)?;
+        self.start.try_visit(visitor)?;
+        self.start_end_comma.try_visit(visitor)?;
+        self.end.try_visit(visitor)?;
+        self.end_step_comma.try_visit(visitor)?;
+        self.step.try_visit(visitor)?;
+        self.do_token.try_visit(visitor)?;
+        self.block.try_visit(visitor)?;
+        self.end_token.try_visit(visitor)?;
+
+        visitor.visit_numeric_for_end(self)
+    }
+}
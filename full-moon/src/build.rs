@@ -0,0 +1,130 @@
+use crate::{
+    ast::{
+        punctuated::Punctuated, span::ContainedSpan, Assignment, Block, Call, Expression,
+        FunctionArgs, FunctionCall, If, LocalAssignment, Prefix, Stmt, Suffix, Var,
+    },
+    node::Node,
+    tokenizer::{Token, TokenReference, TokenType},
+    visitors::{VisitMut, VisitorMut},
+};
+
+fn symbol(text: &str) -> TokenReference {
+    TokenReference::symbol(text).unwrap()
+}
+
+fn comma_separated<T>(values: Vec<T>) -> Punctuated<T> {
+    Punctuated::from_values(values, symbol(", "))
+}
+
+// Adds a newline to the trailing trivia of the last token of a node
+struct TrailingNewline {
+    tokens_left: usize,
+}
+
+impl VisitorMut for TrailingNewline {
+    fn visit_token_reference(&mut self, token: TokenReference) -> TokenReference {
+        self.tokens_left -= 1;
+        if self.tokens_left != 0 {
+            return token;
+        }
+
+        let mut trailing_trivia: Vec<Token> = token.trailing_trivia().cloned().collect();
+        trailing_trivia.push(Token::new(TokenType::newlines(1)));
+        token.with_trailing_trivia(trailing_trivia)
+    }
+}
+
+/// Creates a local assignment of a single name, such as `local x = 1`
+///
+/// ```rust
+/// # use full_moon::{ast::Expression, build};
+/// let local = build::local("x", Expression::number("1"));
+/// assert_eq!(local.to_string(), "local x = 1");
+/// ```
+pub fn local(name: &str, value: Expression) -> LocalAssignment {
+    LocalAssignment::new(comma_separated(vec![TokenReference::identifier(name)]))
+        .with_equal_token(Some(symbol(" = ")))
+        .with_expressions(comma_separated(vec![value]))
+}
+
+/// Creates an assignment, such as `x, y = 1, 2`
+///
+/// ```rust
+/// # use full_moon::{ast::{Expression, Var}, build, tokenizer::TokenReference};
+/// let assignment = build::assign(
+///     vec![
+///         Var::Name(TokenReference::identifier("x")),
+///         Var::Name(TokenReference::identifier("y")),
+///     ],
+///     vec![Expression::number("1"), Expression::number("2")],
+/// );
+/// assert_eq!(assignment.to_string(), "x, y = 1, 2");
+/// ```
+pub fn assign(variables: Vec<Var>, values: Vec<Expression>) -> Assignment {
+    Assignment::new(comma_separated(variables), comma_separated(values))
+}
+
+/// Creates a call of a function by name, such as `print(1, 2)`
+///
+/// ```rust
+/// # use full_moon::{ast::Expression, build};
+/// let call = build::call("print", vec![Expression::number("1"), Expression::number("2")]);
+/// assert_eq!(call.to_string(), "print(1, 2)");
+/// ```
+pub fn call(name: &str, arguments: Vec<Expression>) -> FunctionCall {
+    FunctionCall::new(Prefix::Name(TokenReference::identifier(name))).with_suffixes(vec![
+        Suffix::Call(Call::AnonymousCall(FunctionArgs::Parentheses {
+            parentheses: ContainedSpan::new(symbol("("), symbol(")")),
+            arguments: comma_separated(arguments),
+        })),
+    ])
+}
+
+/// Creates a block of the given statements, with each statement on its own line.
+/// No newline is added after the last statement, as the statements that contain blocks,
+/// such as [`if_then`], add their own.
+///
+/// ```rust
+/// # use full_moon::{ast::{Expression, Stmt}, build};
+/// let block = build::block(vec![
+///     Stmt::LocalAssignment(build::local("x", Expression::number("1"))),
+///     Stmt::FunctionCall(build::call("print", Vec::new())),
+/// ]);
+/// assert_eq!(block.to_string(), "local x = 1\nprint()");
+/// ```
+pub fn block(stmts: Vec<Stmt>) -> Block {
+    let last_index = stmts.len().saturating_sub(1);
+
+    Block::new().with_stmts(
+        stmts
+            .into_iter()
+            .enumerate()
+            .map(|(index, stmt)| {
+                let tokens_left = stmt.tokens().count();
+                if index == last_index || tokens_left == 0 {
+                    return (stmt, None);
+                }
+
+                (stmt.visit_mut(&mut TrailingNewline { tokens_left }), None)
+            })
+            .collect(),
+    )
+}
+
+/// Creates an `if` statement without any `elseif` or `else` branches, such as
+/// `if x then print(x) end`, with the block on its own lines.
+/// Use [`block`] to create a block with each statement on its own line.
+///
+/// ```rust
+/// # use full_moon::{ast::{Expression, Stmt}, build, tokenizer::TokenReference};
+/// let if_statement = build::if_then(
+///     Expression::Symbol(TokenReference::symbol("true").unwrap()),
+///     build::block(vec![Stmt::FunctionCall(build::call("print", Vec::new()))]),
+/// );
+/// assert_eq!(if_statement.to_string(), "if true then\nprint()\nend");
+/// ```
+pub fn if_then(condition: Expression, block: Block) -> If {
+    If::new(condition)
+        .with_then_token(symbol(" then\n"))
+        .with_block(block)
+}
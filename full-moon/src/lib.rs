@@ -9,6 +9,9 @@
 /// Utilities for ASTs (Abstract Syntax Trees). Contains all nodes used by Full Moon (such as blocks).
 pub mod ast;
 
+/// Functions for building common nodes with sensible whitespace, for generating code.
+pub mod build;
+
 /// Contains the `Node` trait, implemented on all nodes
 // pub mod node;
 
@@ -19,9 +22,20 @@ pub mod tokenizer;
 /// Used to create visitors that recurse through [`Ast`](ast::Ast) nodes.
 pub mod visitors;
 
+/// Used to resolve which local variable an identifier refers to, for tools such as renaming.
+pub mod resolver;
+
+/// Utilities for testing tools built on top of full-moon, such as checking that code round trips.
+/// Only available when the "test-util" feature flag is enabled.
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
+mod normalize;
 mod private;
 mod util;
 
+pub use normalize::PrintOptions;
+
 use full_moon_common::{
     language::Language,
     short_string::ShortString,
@@ -29,7 +43,7 @@ use full_moon_common::{
     node,
 };
 
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, io};
 
 #[cfg(all(test, not(feature = "serde")))]
 compile_error!("Serde feature must be enabled for tests");
@@ -52,6 +66,58 @@ pub fn parse<L: Language>(code: &str) -> Result<ast::Ast, Vec<Error>> {
     parse_fallible::<L>(code).into_result()
 }
 
+/// Creates an [`Ast`](ast::Ast) from Lua code, using Lua versions chosen at runtime rather than
+/// through a [`Language`] type. This lets a single binary parse different dialects.
+///
+/// Both tokenizing and parsing follow `version`, for example `goto` is only a keyword when
+/// Lua 5.2 is included, and `+=`, `continue`, and type annotations are only accepted when
+/// Luau is included.
+///
+/// # Errors
+/// Returns the same errors as [`parse`], including for any syntax not available in `version`.
+///
+/// ```rust
+/// # use full_moon::ast::LuaVersion;
+/// assert!(full_moon::parse_version("local x = 1", LuaVersion::lua51()).is_ok());
+/// # #[cfg(feature = "luau")]
+/// assert!(full_moon::parse_version("x += 1", LuaVersion::lua51()).is_err());
+/// # #[cfg(feature = "luau")]
+/// assert!(full_moon::parse_version("local x: number = 1", LuaVersion::lua51()).is_err());
+/// # #[cfg(feature = "luau")]
+/// assert!(full_moon::parse_version("x += 1", LuaVersion::luau()).is_ok());
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn parse_version(code: &str, version: ast::LuaVersion) -> Result<ast::Ast, Vec<Error>> {
+    ast::AstResult::parse_fallible_version(code, version).into_result()
+}
+
+/// Creates an [`Ast`](ast::Ast) from Lua code read out of a reader, such as a file.
+/// Otherwise behaves the same as [`parse`].
+///
+/// The entire source is buffered before parsing, as the lexer needs random access to the code,
+/// and the resulting [`Ast`](ast::Ast) holds its own copy of every token. This means peak memory
+/// is not reduced compared to reading into a `String` yourself, this only saves the boilerplate.
+///
+/// # Errors
+/// Returns an outer error if reading fails, or if the code is not valid UTF-8.
+/// Otherwise, returns the same result as [`parse`] would.
+///
+/// ```rust
+/// # fn main() -> std::io::Result<()> {
+/// let source = "local x = 1".as_bytes();
+/// assert!(full_moon::parse_reader(source)?.is_ok());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn parse_reader<L: Language, R: io::Read>(
+    mut reader: R,
+) -> io::Result<Result<ast::Ast, Vec<Error>>> {
+    let mut code = String::new();
+    reader.read_to_string(&mut code)?;
+    Ok(parse::<L>(&code))
+}
+
 /// Given code and a pinned Lua version, will produce an [`ast::AstResult`].
 /// This AstResult always produces some [`Ast`](ast::Ast), regardless of errors.
 /// If a partial Ast is produced (i.e. if there are any errors), a few guarantees are lost.
@@ -64,11 +130,120 @@ pub fn parse<L: Language>(code: &str) -> Result<ast::Ast, Vec<Error>> {
 /// [`LocalAssignment`](ast::LocalAssignment) that would print to `local x =`.
 /// 3. There are no stability guarantees for partial Ast results, but they are consistent
 /// within the same exact version of full-moon.
+///
+/// Use [`AstResult::is_lossless`](ast::AstResult::is_lossless) to check whether any of these
+/// guarantees were actually lost.
 pub fn parse_fallible<L: Language>(code: &str) -> ast::AstResult {
     ast::AstResult::parse_fallible::<L>(code)
 }
 
+/// Creates an [`Expression`](ast::Expression) from code made up of exactly one expression,
+/// such as `1 + 2` or `call(x)`, rather than a whole chunk of statements.
+/// Useful for REPLs, or for building nodes to insert into an existing [`Ast`](ast::Ast).
+/// Whitespace and comments at the very end of the code, after the line the expression ends on,
+/// are not kept, as they belong to the EOF token rather than the expression.
+///
+/// # Errors
+/// Returns the same errors as [`parse`], as well as an error if the code is not an expression,
+/// or if there are any tokens left over after the expression.
+///
+/// ```rust
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let expression = full_moon::parse_expression("1 + call(x)")?;
+/// assert_eq!(expression.to_string(), "1 + call(x)");
+/// assert!(full_moon::parse_expression("1 + 2 3").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn parse_expression<L: Language>(code: &str) -> Result<ast::Expression, Vec<Error>> {
+    ast::parse_single_expression::<L>(code)
+}
+
+/// Creates a [`Stmt`](ast::Stmt) from code made up of exactly one statement, such as `local x = 1`.
+/// Otherwise behaves the same as [`parse_expression`].
+/// Statements that can only end a block, such as `return` and `break`, are [`LastStmt`](ast::LastStmt)s
+/// rather than statements, and so are not accepted.
+///
+/// # Errors
+/// Returns the same errors as [`parse`], as well as an error if the code is not a statement,
+/// or if there are any tokens left over after the statement, including a semicolon.
+///
+/// ```rust
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let stmt = full_moon::parse_statement("local x = 1")?;
+/// assert_eq!(stmt.to_string(), "local x = 1");
+/// assert!(full_moon::parse_statement("local x = 1 local y = 2").is_err());
+/// assert!(full_moon::parse_statement("return 1").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn parse_statement<L: Language>(code: &str) -> Result<ast::Stmt, Vec<Error>> {
+    ast::parse_single_statement::<L>(code)
+}
+
 /// Prints back Lua code from an [`Ast`](ast::Ast)
 pub fn print(ast: &ast::Ast) -> String {
-    format!("{}{}", ast.nodes(), ast.eof())
+    let mut output = Vec::new();
+    print_to(&mut output, ast).expect("writing to a Vec should never fail");
+    String::from_utf8(output).expect("printed code should always be valid UTF-8")
+}
+
+/// Prints back Lua code from an [`Ast`](ast::Ast) with normalized whitespace, rather than
+/// the whitespace from the original code. Every statement is put on its own line, blocks are
+/// indented according to `options`, and tokens are separated by at most one space, such as one
+/// space on either side of binary operators. Comments are kept.
+///
+/// This is not a full formatter, as lines are never wrapped or joined, but it gives consistent
+/// output for code such as generated code. Use [`print`] to get back the exact original code.
+///
+/// ```rust
+/// # use full_moon::PrintOptions;
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("local  x=1+2 if x then print(x)end")?;
+/// assert_eq!(
+///     full_moon::print_normalized(&ast, PrintOptions::new().with_indent_width(2)),
+///     "local x = 1 + 2\nif x then\n  print(x)\nend\n",
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn print_normalized(ast: &ast::Ast, options: PrintOptions) -> String {
+    normalize::Normalizer::new(options).print(ast)
+}
+
+/// Prints back Lua code from an [`Ast`](ast::Ast) with as little whitespace as possible.
+/// Comments and all other trivia are removed, and tokens are only separated by a space when they
+/// would otherwise be read as something else. For example, `local x` still has a space, and
+/// `a - -b` becomes `a- -b` instead of `a--b`, which would start a comment.
+///
+/// ```rust
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("-- comment\nlocal x = 1 + 2\nreturn x - -1")?;
+/// assert_eq!(full_moon::print_minified(&ast), "local x=1+2 return x- -1");
+/// # Ok(())
+/// # }
+/// ```
+pub fn print_minified(ast: &ast::Ast) -> String {
+    normalize::minify(ast)
+}
+
+/// Prints back Lua code from an [`Ast`](ast::Ast) directly into a writer, such as a file,
+/// without building the entire code as a `String` first.
+///
+/// # Errors
+/// Returns any error that occurs while writing into `writer`.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let ast = full_moon::parse("local x = 1").map_err(|_| "couldn't parse")?;
+/// let mut output = Vec::new();
+/// full_moon::print_to(&mut output, &ast)?;
+/// assert_eq!(output, b"local x = 1");
+/// # Ok(())
+/// # }
+/// ```
+pub fn print_to<W: io::Write>(writer: &mut W, ast: &ast::Ast) -> io::Result<()> {
+    write!(writer, "{}{}", ast.nodes(), ast.eof())
 }
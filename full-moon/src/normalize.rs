@@ -0,0 +1,354 @@
+use crate::{
+    ast::{Ast, Block, LastStmt, Stmt, UnOp},
+    tokenizer::{Symbol, Token, TokenReference, TokenType},
+    visitors::Visitor,
+};
+
+#[cfg(feature = "lua52")]
+use crate::ast::lua52::Label;
+#[cfg(feature = "lua54")]
+use crate::ast::lua54::Attribute;
+#[cfg(feature = "luau")]
+use crate::{ast::types::TypeSpecifier, tokenizer::InterpolatedStringKind};
+
+/// Options for [`print_normalized`](crate::print_normalized), controlling how the code is laid out.
+///
+/// ```rust
+/// # use full_moon::PrintOptions;
+/// let options = PrintOptions::new().with_indent_width(2);
+/// assert_eq!(options.indent_width(), 2);
+/// assert!(!options.use_tabs());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PrintOptions {
+    indent_width: usize,
+    use_tabs: bool,
+}
+
+impl PrintOptions {
+    /// Creates the default options, which indent blocks with 4 spaces
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The amount of spaces each block is indented with. Unused when indenting with tabs.
+    pub fn indent_width(&self) -> usize {
+        self.indent_width
+    }
+
+    /// Whether blocks are indented with one tab each rather than with spaces
+    pub fn use_tabs(&self) -> bool {
+        self.use_tabs
+    }
+
+    /// Returns new options with the given amount of spaces to indent each block with
+    pub fn with_indent_width(self, indent_width: usize) -> Self {
+        Self {
+            indent_width,
+            ..self
+        }
+    }
+
+    /// Returns new options with whether to indent blocks with tabs rather than spaces
+    pub fn with_use_tabs(self, use_tabs: bool) -> Self {
+        Self { use_tabs, ..self }
+    }
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            use_tabs: false,
+        }
+    }
+}
+
+// Prints every token in order, throwing away the original whitespace.
+// Statements and the ends of blocks are put on new lines, everything else is separated by
+// at most one space, depending on what is on either side.
+pub(crate) struct Normalizer {
+    options: PrintOptions,
+    output: String,
+    // The amount of blocks currently inside of, including the root block
+    depth: usize,
+    // Whether the next token should start a new line
+    newline: bool,
+    // Whether the last thing written allows a space after it
+    space_after: bool,
+    // Whether the last token written ends a value, meaning a `(` or `[` after it is a call or index
+    after_value: bool,
+    // Whether the last thing written was a comment
+    after_comment: bool,
+    // Whether the trivia since the last token written has a line break
+    line_break: bool,
+    // Whether the next token is the operator of a unary operation
+    unary_operator: bool,
+    // Whether the next `:` starts a type specifier rather than a method call
+    type_specifier: bool,
+    // The tokens left in a `::label::` or `<attribute>`, which have no spaces inside of them
+    enclosed_tokens: u8,
+}
+
+impl Normalizer {
+    pub(crate) fn new(options: PrintOptions) -> Self {
+        Self {
+            options,
+            output: String::new(),
+            depth: 0,
+            newline: false,
+            space_after: false,
+            after_value: false,
+            after_comment: false,
+            line_break: false,
+            unary_operator: false,
+            type_specifier: false,
+            enclosed_tokens: 0,
+        }
+    }
+
+    pub(crate) fn print(mut self, ast: &Ast) -> String {
+        self.visit_ast(ast);
+
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+
+        self.output
+    }
+
+    fn write(&mut self, text: &str, space_before: bool, space_after: bool) {
+        if self.newline && !self.output.is_empty() {
+            self.output.push('\n');
+
+            let level = self.depth.saturating_sub(1);
+            if self.options.use_tabs {
+                self.output.extend(std::iter::repeat('\t').take(level));
+            } else {
+                self.output
+                    .extend(std::iter::repeat(' ').take(level * self.options.indent_width));
+            }
+        } else if !self.output.is_empty()
+            && ((space_before && self.space_after) || self.would_merge(text))
+        {
+            self.output.push(' ');
+        }
+
+        self.output.push_str(text);
+        self.newline = false;
+        self.line_break = false;
+        self.space_after = space_after;
+    }
+
+    fn would_merge(&self, text: &str) -> bool {
+        would_merge(&self.output, text)
+    }
+
+    fn write_token(&mut self, token: &Token) {
+        let unary_operator = std::mem::take(&mut self.unary_operator);
+        let mut space_before = true;
+        let mut space_after = true;
+
+        match token.token_type() {
+            TokenType::Eof => return,
+
+            TokenType::Symbol { symbol } => match symbol {
+                Symbol::LeftParen | Symbol::LeftBracket => {
+                    space_before = !self.after_value;
+                    space_after = false;
+                }
+
+                Symbol::LeftBrace => space_after = false,
+
+                Symbol::RightParen
+                | Symbol::RightBracket
+                | Symbol::RightBrace
+                | Symbol::Comma
+                | Symbol::Semicolon => space_before = false,
+
+                Symbol::Dot => {
+                    space_before = false;
+                    space_after = false;
+                }
+
+                Symbol::Colon => {
+                    space_before = false;
+                    space_after = std::mem::take(&mut self.type_specifier);
+                }
+
+                #[cfg(feature = "luau")]
+                Symbol::QuestionMark => space_before = false,
+
+                Symbol::Not => {}
+
+                _ if unary_operator => space_after = false,
+
+                _ => {}
+            },
+
+            #[cfg(feature = "luau")]
+            TokenType::InterpolatedString { kind, .. } => match kind {
+                InterpolatedStringKind::Begin => space_after = false,
+                InterpolatedStringKind::Middle => {
+                    space_before = false;
+                    space_after = false;
+                }
+                InterpolatedStringKind::End => space_before = false,
+                InterpolatedStringKind::Simple => {}
+            },
+
+            _ => {}
+        }
+
+        match self.enclosed_tokens {
+            3 => space_after = false,
+            1 => space_before = false,
+            _ => {}
+        }
+
+        self.enclosed_tokens = self.enclosed_tokens.saturating_sub(1);
+
+        self.write(&token.to_string(), space_before, space_after);
+        self.after_comment = false;
+        self.after_value = matches!(
+            token.token_type(),
+            TokenType::Identifier { .. }
+                | TokenType::StringLiteral { .. }
+                | TokenType::Symbol {
+                    symbol: Symbol::RightParen
+                        | Symbol::RightBracket
+                        | Symbol::RightBrace
+                        | Symbol::Function
+                }
+        );
+    }
+
+    fn write_trivia(&mut self, trivia: &Token) {
+        match trivia.token_type() {
+            TokenType::SingleLineComment { .. } | TokenType::Shebang { .. } => {
+                self.write_comment(trivia);
+                self.newline = true;
+            }
+
+            TokenType::MultiLineComment { .. } => self.write_comment(trivia),
+
+            // Comments stay on their own lines if they were on their own lines
+            TokenType::Whitespace { characters } if characters.contains('\n') => {
+                if self.after_comment {
+                    self.newline = true;
+                } else {
+                    self.line_break = true;
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn write_comment(&mut self, comment: &Token) {
+        if self.line_break {
+            self.newline = true;
+        }
+
+        self.write(&comment.to_string(), true, true);
+        self.after_comment = true;
+        self.after_value = false;
+    }
+}
+
+// Prints every token in order without any trivia, with a space only where two tokens
+// would otherwise be read as something else.
+pub(crate) fn minify(ast: &Ast) -> String {
+    let mut output = String::new();
+    let mut after_number = false;
+
+    for token in ast.tokens() {
+        let text = token.token().to_string();
+
+        // `1 ..x` would be read as the number `1.`
+        if (after_number && text.starts_with('.')) || would_merge(&output, &text) {
+            output.push(' ');
+        }
+
+        output.push_str(&text);
+        after_number = matches!(token.token_type(), TokenType::Number { .. });
+    }
+
+    output
+}
+
+// Whether the text would turn into something else if written right after the output,
+// such as `- -x` turning into the comment `--x`, or `local x` turning into `localx`
+fn would_merge(output: &str, text: &str) -> bool {
+    let (Some(last), Some(first)) = (output.chars().next_back(), text.chars().next()) else {
+        return false;
+    };
+
+    let is_word = |character: char| character.is_alphanumeric() || character == '_';
+
+    (is_word(last) && is_word(first))
+        || matches!(
+            (last, first),
+            ('-', '-' | '>' | '=')
+                | ('[', '[' | '=')
+                | (
+                    '.' | '/' | ':' | '<' | '>',
+                    '.' | '/' | ':' | '<' | '>' | '='
+                )
+                | ('=' | '~' | '+' | '*' | '%' | '^', '=')
+        )
+}
+
+impl Visitor for Normalizer {
+    fn visit_block(&mut self, _: &Block) {
+        self.depth += 1;
+    }
+
+    fn visit_block_end(&mut self, block: &Block) {
+        self.depth -= 1;
+
+        // Keeps empty blocks on one line, such as `function() end`
+        if !block.is_empty() {
+            self.newline = true;
+        }
+    }
+
+    fn visit_stmt(&mut self, _: &Stmt) {
+        self.newline = true;
+    }
+
+    fn visit_last_stmt(&mut self, _: &LastStmt) {
+        self.newline = true;
+    }
+
+    fn visit_un_op(&mut self, _: &UnOp) {
+        self.unary_operator = true;
+    }
+
+    #[cfg(feature = "luau")]
+    fn visit_type_specifier(&mut self, _: &TypeSpecifier) {
+        self.type_specifier = true;
+    }
+
+    #[cfg(feature = "lua52")]
+    fn visit_label(&mut self, _: &Label) {
+        self.enclosed_tokens = 3;
+    }
+
+    #[cfg(feature = "lua54")]
+    fn visit_attribute(&mut self, _: &Attribute) {
+        self.enclosed_tokens = 3;
+    }
+
+    fn visit_token_reference(&mut self, token: &TokenReference) {
+        for trivia in token.leading_trivia() {
+            self.write_trivia(trivia);
+        }
+
+        self.write_token(token.token());
+
+        for trivia in token.trailing_trivia() {
+            self.write_trivia(trivia);
+        }
+    }
+}
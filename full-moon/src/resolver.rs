@@ -0,0 +1,234 @@
+use crate::{ast::*, tokenizer::TokenReference, visitors::Visitor};
+use std::{
+    collections::{HashMap, HashSet},
+    ptr,
+};
+
+/// Resolves which local variable each identifier in an [`Ast`] refers to, following Lua's
+/// scoping rules. Names declared in a block, such as by `local x` or as the parameters of
+/// a function, are only visible until the end of that block, and may be shadowed by a
+/// later declaration of the same name.
+///
+/// ```rust
+/// # use full_moon::resolver::Resolver;
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("local x = 1 do local x = x end print(x)")?;
+/// let resolver = Resolver::new(&ast);
+///
+/// let names: Vec<_> = ast.tokens().filter(|token| token.text() == "x").collect();
+/// let declared_at = |token| {
+///     let declaration = resolver.resolve(token)?;
+///     Some(declaration.start_position().bytes())
+/// };
+///
+/// assert_eq!(declared_at(names[0]), Some(6)); // local x = 1
+/// assert_eq!(declared_at(names[1]), Some(21)); // local x = x
+/// assert_eq!(declared_at(names[2]), Some(6)); // = x
+/// assert_eq!(declared_at(names[3]), Some(6)); // print(x)
+///
+/// let print = ast.tokens().find(|token| token.text() == "print").unwrap();
+/// assert_eq!(resolver.resolve(print), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Resolver {
+    declarations: HashMap<usize, TokenReference>,
+    globals: HashSet<usize>,
+}
+
+impl Resolver {
+    /// Walks the [`Ast`], resolving every identifier in it.
+    /// The Ast must have positions, such as one returned by [`parse`](crate::parse), as
+    /// identifiers are told apart by where they start.
+    pub fn new(ast: &Ast) -> Self {
+        let mut visitor = ScopeVisitor::default();
+        visitor.visit_ast(ast);
+
+        Self {
+            declarations: visitor.declarations,
+            globals: visitor.globals,
+        }
+    }
+
+    /// The name that declares the local variable the identifier refers to, such as the `x` in
+    /// `local x`, a parameter of a function, or a name in a `for` loop. Names that declare a
+    /// local variable resolve to themselves. The implicit `self` parameter of a method resolves
+    /// to the name of the method, such as `c` in `function a.b:c()`.
+    ///
+    /// Returns `None` if the identifier refers to a global variable, or isn't a variable at all,
+    /// such as the `y` in `x.y`. Use [`Resolver::is_global`] to tell the two apart.
+    pub fn resolve(&self, identifier: &TokenReference) -> Option<&TokenReference> {
+        self.declarations
+            .get(&identifier.token().start_position().bytes())
+    }
+
+    /// Returns true if the identifier is used as a variable, but isn't declared as a local
+    /// variable anywhere it can be seen from, such as `x` in both `print(x)` and `x = 1`
+    pub fn is_global(&self, identifier: &TokenReference) -> bool {
+        self.globals
+            .contains(&identifier.token().start_position().bytes())
+    }
+}
+
+#[derive(Default)]
+struct ScopeVisitor {
+    // Every name that has been declared in each block currently being visited, innermost last
+    scopes: Vec<HashMap<String, TokenReference>>,
+    // Names that will be declared once the given block is entered, such as function parameters
+    pending: Vec<(*const Block, String, TokenReference)>,
+    // The blocks of `repeat` loops, which stay in scope for the `until` condition
+    repeat_blocks: Vec<*const Block>,
+    declarations: HashMap<usize, TokenReference>,
+    globals: HashSet<usize>,
+}
+
+impl ScopeVisitor {
+    fn declare(&mut self, name: String, declaration: &TokenReference) {
+        self.declarations.insert(
+            declaration.token().start_position().bytes(),
+            declaration.clone(),
+        );
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, declaration.clone());
+        }
+    }
+
+    fn declare_in(&mut self, block: &Block, name: String, declaration: &TokenReference) {
+        self.pending
+            .push((block as *const Block, name, declaration.clone()));
+    }
+
+    fn resolve(&mut self, identifier: &TokenReference) {
+        let name = identifier.token().to_string();
+        let start = identifier.token().start_position().bytes();
+
+        match self.scopes.iter().rev().find_map(|scope| scope.get(&name)) {
+            Some(declaration) => {
+                self.declarations.insert(start, declaration.clone());
+            }
+            None => {
+                self.globals.insert(start);
+            }
+        }
+    }
+}
+
+impl Visitor for ScopeVisitor {
+    fn visit_block(&mut self, block: &Block) {
+        self.scopes.push(HashMap::new());
+
+        // Pending names are declared in the order they were found, so that a parameter
+        // named `self` shadows the implicit one of a method, as it does in Lua
+        let first_pending = self
+            .pending
+            .iter()
+            .rposition(|(pending_block, ..)| !ptr::eq(*pending_block, block))
+            .map_or(0, |index| index + 1);
+
+        for (_, name, declaration) in self.pending.split_off(first_pending) {
+            self.declare(name, &declaration);
+        }
+    }
+
+    fn visit_block_end(&mut self, block: &Block) {
+        if self.repeat_blocks.last().copied() != Some(block as *const Block) {
+            self.scopes.pop();
+        }
+    }
+
+    fn visit_repeat(&mut self, repeat: &Repeat) {
+        self.repeat_blocks.push(repeat.block());
+    }
+
+    fn visit_repeat_end(&mut self, _: &Repeat) {
+        self.repeat_blocks.pop();
+        self.scopes.pop();
+    }
+
+    fn visit_function_body(&mut self, body: &FunctionBody) {
+        for parameter in body.parameters() {
+            if let Parameter::Name(name) = parameter {
+                self.declare_in(body.block(), name.token().to_string(), name);
+            }
+        }
+    }
+
+    fn visit_function_declaration(&mut self, declaration: &FunctionDeclaration) {
+        if let Some(name) = declaration.name().names().iter().next() {
+            self.resolve(name);
+        }
+
+        if let Some(method_name) = declaration.name().method_name() {
+            self.declare_in(declaration.body().block(), "self".to_owned(), method_name);
+        }
+    }
+
+    fn visit_generic_for(&mut self, generic_for: &GenericFor) {
+        for name in generic_for.names() {
+            self.declare_in(generic_for.block(), name.token().to_string(), name);
+        }
+    }
+
+    fn visit_local_assignment_end(&mut self, local_assignment: &LocalAssignment) {
+        // Names are declared after the expressions are visited, as `local x = x` refers to
+        // the `x` from before
+        for name in local_assignment.names() {
+            self.declare(name.token().to_string(), name);
+        }
+    }
+
+    fn visit_local_function(&mut self, local_function: &LocalFunction) {
+        // Unlike `local x = function() end`, the function can refer to itself
+        let name = local_function.name();
+        self.declare(name.token().to_string(), name);
+    }
+
+    fn visit_numeric_for(&mut self, numeric_for: &NumericFor) {
+        let index_variable = numeric_for.index_variable();
+        self.declare_in(
+            numeric_for.block(),
+            index_variable.token().to_string(),
+            index_variable,
+        );
+    }
+
+    fn visit_prefix(&mut self, prefix: &Prefix) {
+        if let Prefix::Name(name) = prefix {
+            self.resolve(name);
+        }
+    }
+
+    fn visit_var(&mut self, var: &Var) {
+        if let Var::Name(name) = var {
+            self.resolve(name);
+        }
+    }
+}
+
+impl Ast {
+    /// Every identifier used as a variable that isn't declared as a local variable, function
+    /// parameter, or loop variable anywhere it can be seen from, in the order they appear.
+    /// This includes global variables being assigned to, such as `x` in `x = 1`.
+    /// Linters can use this to find undeclared globals, or typos in the names of locals.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Vec<full_moon::Error>> {
+    /// let ast = full_moon::parse("local value = 1 count = valeu + 1 print(count)")?;
+    /// let free_variables: Vec<_> = ast
+    ///     .free_variables()
+    ///     .into_iter()
+    ///     .map(|token| token.text().to_owned())
+    ///     .collect();
+    /// assert_eq!(free_variables, vec!["count", "valeu", "print", "count"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn free_variables(&self) -> Vec<&TokenReference> {
+        let resolver = Resolver::new(self);
+        self.tokens()
+            .filter(|token| resolver.is_global(token))
+            .collect()
+    }
+}
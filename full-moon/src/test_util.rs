@@ -0,0 +1,33 @@
+use crate::{parse_fallible, print};
+use full_moon_common::language::Language;
+
+/// Parses `code` with [`parse_fallible`], prints it back with [`print`], and asserts that the
+/// printed code is exactly the same as `code`. Code that fails to parse is skipped, as partial
+/// Asts are not guaranteed to round trip.
+///
+/// Useful for running over a corpus of code, such as when fuzzing, to check that full-moon
+/// reproduces it losslessly.
+///
+/// # Panics
+/// Panics if the code parses without errors, but doesn't print back to the same code.
+///
+/// ```rust
+/// full_moon::test_util::assert_round_trips("local x = 1 -- comment\nprint(x)");
+///
+/// // Code that doesn't parse is ignored
+/// full_moon::test_util::assert_round_trips("local x = ");
+/// ```
+#[track_caller]
+pub fn assert_round_trips<L: Language>(code: &str) {
+    let result = parse_fallible::<L>(code);
+
+    if !result.errors().is_empty() {
+        return;
+    }
+
+    let printed = print(result.ast());
+    assert!(
+        printed == code,
+        "code did not round trip\n\ninput:\n{code}\n\noutput:\n{printed}"
+    );
+}
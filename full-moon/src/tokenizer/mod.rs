@@ -4,5 +4,7 @@ pub use lexer::*;
 mod structs;
 pub use structs::*;
 
+pub use full_moon_common::tokenizer::tokens;
+
 #[cfg(feature = "luau")]
 mod interpolated_strings;
@@ -1,8 +1,9 @@
 use crate::{
     ast::{span::ContainedSpan, *},
     private::Sealed,
-    tokenizer::{Token, TokenReference},
+    tokenizer::{Token, TokenKind, TokenReference},
 };
+use std::collections::HashMap;
 
 #[cfg(feature = "lua52")]
 use crate::ast::lua52::*;
@@ -10,3 +11,154 @@ use crate::ast::lua52::*;
 use crate::ast::lua54::*;
 #[cfg(feature = "luau")]
 use crate::ast::types::*;
+
+/// A [`Visitor`] that collects every node of type `N` it visits, in the order they are visited.
+/// Use [`collect`] to run one over an entire [`Ast`].
+#[derive(Clone, Debug)]
+pub struct Collector<N> {
+    nodes: Vec<N>,
+}
+
+impl<N> Collector<N> {
+    /// Creates a collector that has not collected any nodes yet
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// The nodes collected so far
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Consumes the collector, returning the collected nodes
+    pub fn into_nodes(self) -> Vec<N> {
+        self.nodes
+    }
+}
+
+impl<N> Default for Collector<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_collector {
+    ($($visit_name:ident => $ast_type:ty,)+) => {
+        $(
+            impl Visitor for Collector<$ast_type> {
+                fn $visit_name(&mut self, node: &$ast_type) {
+                    self.nodes.push(node.clone());
+                }
+            }
+        )+
+    };
+}
+
+impl_collector! {
+    visit_assignment => Assignment,
+    visit_block => Block,
+    visit_do => Do,
+    visit_else_if => ElseIf,
+    visit_expression => Expression,
+    visit_field => Field,
+    visit_function_args => FunctionArgs,
+    visit_function_body => FunctionBody,
+    visit_function_call => FunctionCall,
+    visit_function_declaration => FunctionDeclaration,
+    visit_generic_for => GenericFor,
+    visit_if => If,
+    visit_index => Index,
+    visit_last_stmt => LastStmt,
+    visit_local_assignment => LocalAssignment,
+    visit_local_function => LocalFunction,
+    visit_method_call => MethodCall,
+    visit_numeric_for => NumericFor,
+    visit_parameter => Parameter,
+    visit_repeat => Repeat,
+    visit_return => Return,
+    visit_stmt => Stmt,
+    visit_suffix => Suffix,
+    visit_table_constructor => TableConstructor,
+    visit_var => Var,
+    visit_var_expression => VarExpression,
+    visit_while => While,
+}
+
+/// Returns every node of type `N` in the [`Ast`], in the order they are visited.
+/// Nodes are cloned, since the references given to [`Visitor`] functions can't outlive the call.
+///
+/// ```rust
+/// # use full_moon::ast;
+/// # use full_moon::visitors::collect;
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("local x = 1; local y, z = 2, 3")?;
+/// let local_assignments = collect::<ast::LocalAssignment>(&ast);
+/// assert_eq!(local_assignments.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn collect<N>(ast: &Ast) -> Vec<N>
+where
+    Collector<N>: Visitor,
+{
+    let mut collector = Collector::new();
+    collector.visit_ast(ast);
+    collector.into_nodes()
+}
+
+/// A [`Visitor`] that counts how many tokens of each [`TokenKind`] it visits, including trivia
+/// such as whitespace and comments. Use [`count_tokens`] to run one over an entire [`Ast`].
+#[derive(Clone, Debug, Default)]
+pub struct TokenCounter {
+    counts: HashMap<TokenKind, usize>,
+}
+
+impl TokenCounter {
+    /// Creates a counter that has not counted any tokens yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many tokens of the given kind have been counted so far
+    pub fn count(&self, kind: TokenKind) -> usize {
+        self.counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// The amount of tokens of each kind counted so far.
+    /// Kinds that have not been seen are not included.
+    pub fn counts(&self) -> &HashMap<TokenKind, usize> {
+        &self.counts
+    }
+
+    /// Consumes the counter, returning the amount of tokens of each kind
+    pub fn into_counts(self) -> HashMap<TokenKind, usize> {
+        self.counts
+    }
+}
+
+impl Visitor for TokenCounter {
+    fn visit_token(&mut self, token: &Token) {
+        *self.counts.entry(token.token_kind()).or_insert(0) += 1;
+    }
+}
+
+/// Returns how many tokens of each [`TokenKind`] are in the [`Ast`], including trivia
+/// such as whitespace and comments, as well as the EOF token.
+/// Kinds that don't appear in the Ast are not included.
+///
+/// ```rust
+/// # use full_moon::tokenizer::TokenKind;
+/// # use full_moon::visitors::count_tokens;
+/// # fn main() -> Result<(), Vec<full_moon::Error>> {
+/// let ast = full_moon::parse("-- comment\nlocal x = 1")?;
+/// let counts = count_tokens(&ast);
+/// assert_eq!(counts[&TokenKind::SingleLineComment], 1);
+/// assert_eq!(counts[&TokenKind::Identifier], 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn count_tokens(ast: &Ast) -> HashMap<TokenKind, usize> {
+    let mut counter = TokenCounter::new();
+    counter.visit_ast(ast);
+    counter.into_counts()
+}
@@ -0,0 +1,136 @@
+use full_moon::{ast, parse};
+
+fn first_stmt(ast: &ast::Ast) -> &ast::Stmt {
+    ast.nodes().stmts().next().expect("expected a statement")
+}
+
+fn bindings<'a, T: ToString + 'a>(
+    bindings: impl Iterator<Item = (T, Option<&'a ast::Expression>)>,
+) -> Vec<(String, Option<String>)> {
+    bindings
+        .map(|(name, value)| {
+            (
+                name.to_string().trim().to_owned(),
+                value.map(|value| value.to_string().trim().to_owned()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_local_assignment_bindings() {
+    let ast = parse("local a, b = 1").unwrap();
+    let ast::Stmt::LocalAssignment(local_assignment) = first_stmt(&ast) else {
+        panic!("expected a local assignment");
+    };
+
+    assert_eq!(
+        bindings(local_assignment.bindings()),
+        vec![
+            ("a".to_owned(), Some("1".to_owned())),
+            ("b".to_owned(), None)
+        ]
+    );
+
+    let ast = parse("local a = 1, 2").unwrap();
+    let ast::Stmt::LocalAssignment(local_assignment) = first_stmt(&ast) else {
+        panic!("expected a local assignment");
+    };
+
+    assert_eq!(
+        bindings(local_assignment.bindings()),
+        vec![("a".to_owned(), Some("1".to_owned()))]
+    );
+
+    let ast = parse("local a, b").unwrap();
+    let ast::Stmt::LocalAssignment(local_assignment) = first_stmt(&ast) else {
+        panic!("expected a local assignment");
+    };
+
+    assert_eq!(
+        bindings(local_assignment.bindings()),
+        vec![("a".to_owned(), None), ("b".to_owned(), None)]
+    );
+}
+
+#[test]
+fn test_assignment_bindings() {
+    let ast = parse("x, y.z, w = f(), 2").unwrap();
+    let ast::Stmt::Assignment(assignment) = first_stmt(&ast) else {
+        panic!("expected an assignment");
+    };
+
+    assert_eq!(
+        bindings(assignment.bindings()),
+        vec![
+            ("x".to_owned(), Some("f()".to_owned())),
+            ("y.z".to_owned(), Some("2".to_owned())),
+            ("w".to_owned(), None),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "lua54")]
+fn test_attributes() {
+    let ast = parse("local a <const>, b, c <close> = 1, 2, 3").unwrap();
+    let ast::Stmt::LocalAssignment(local_assignment) = first_stmt(&ast) else {
+        panic!("expected a local assignment");
+    };
+
+    let attributes = local_assignment
+        .attributes()
+        .map(|attribute| {
+            attribute.map(|attribute| {
+                (
+                    attribute.name_str(),
+                    attribute.is_const(),
+                    attribute.is_close(),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        attributes,
+        vec![
+            Some(("const", true, false)),
+            None,
+            Some(("close", false, true)),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "luau")]
+fn test_compound_assignment_desugar() {
+    let desugar = |code| {
+        let ast = parse(code).unwrap();
+        let ast::Stmt::CompoundAssignment(compound_assignment) = first_stmt(&ast) else {
+            panic!("expected a compound assignment");
+        };
+
+        let desugared = compound_assignment.desugar().to_string();
+        assert!(parse(&desugared).is_ok(), "couldn't reparse {desugared}");
+        desugared
+    };
+
+    assert_eq!(desugar("x += 1"), "x = x + 1");
+    assert_eq!(desugar("x -= 1"), "x = x - 1");
+    assert_eq!(desugar("x *= 2"), "x = x * 2");
+    assert_eq!(desugar("x /= 2"), "x = x / 2");
+    assert_eq!(desugar("x //= 2"), "x = x // 2");
+    assert_eq!(desugar("x %= 2"), "x = x % 2");
+    assert_eq!(desugar("x ^= 2"), "x = x ^ 2");
+    assert_eq!(desugar("x ..= 'a'"), "x = x .. 'a'");
+
+    assert_eq!(desugar("t.x+=1"), "t.x=t.x+1");
+    assert_eq!(desugar("t[k] += 1"), "t[k] = t[k] + 1");
+
+    // The order of operations is kept
+    assert_eq!(desugar("x *= 1 + 2"), "x = x * (1 + 2)");
+    assert_eq!(desugar("x -= 1 - 2"), "x = x - (1 - 2)");
+    assert_eq!(desugar("x += a * b"), "x = x + a * b");
+    assert_eq!(desugar("x ..= a .. b"), "x = x .. a .. b");
+    assert_eq!(desugar("x ^= 2 ^ 3"), "x = x ^ 2 ^ 3");
+}
@@ -0,0 +1,96 @@
+use full_moon::{ast::LuaVersion, parse_fallible, ErrorSeverity};
+
+#[test]
+fn test_error_helpers() {
+    let result = parse_fallible("local x = 1", LuaVersion::new());
+    assert!(!result.has_errors());
+    assert_eq!(result.error_count(), 0);
+    assert_eq!(result.ast_errors().count(), 0);
+
+    let result = parse_fallible("local x = \"unclosed\nlocal y = ", LuaVersion::new());
+    assert!(result.has_errors());
+    assert_eq!(result.error_count(), result.errors().len());
+    assert_eq!(
+        result.ast_errors().count(),
+        result
+            .errors()
+            .iter()
+            .filter(|error| matches!(error, full_moon::Error::AstError(_)))
+            .count()
+    );
+    assert!(result.ast_errors().count() < result.error_count());
+}
+
+#[test]
+fn test_error_severity() {
+    let result = parse_fallible("call(1, 2", LuaVersion::new());
+    assert!(result.has_errors());
+    assert!(result
+        .errors()
+        .iter()
+        .all(|error| error.severity() == ErrorSeverity::Warning));
+    assert!(result.ast_errors().all(|error| error.is_missing_token()));
+
+    let result = parse_fallible("local x = \"unclosed", LuaVersion::new());
+    assert!(result
+        .errors()
+        .iter()
+        .any(|error| error.severity() == ErrorSeverity::Error));
+}
+
+#[test]
+fn test_deduplicated_errors() {
+    let result = parse_fallible("local x = = = =", LuaVersion::new());
+    assert!(result.has_errors());
+    assert_eq!(result.deduplicated_errors().len(), 1);
+
+    let error = &result.deduplicated_errors()[0];
+    assert_eq!(error.range().0, result.errors()[0].range().0);
+    assert_eq!(error.range().1.bytes(), "local x = = = =".len());
+
+    let result = parse_fallible(
+        "local x = = =\nlocal y = 1\nlocal z = = =",
+        LuaVersion::new(),
+    );
+    assert_eq!(result.deduplicated_errors().len(), 2);
+    assert!(result.error_count() >= 2);
+}
+
+#[test]
+fn test_is_lossless() {
+    let result = parse_fallible("local x = 1 -- comment", LuaVersion::new());
+    assert!(result.is_lossless());
+
+    // A phantom `)` is inserted
+    let result = parse_fallible("call(1, 2", LuaVersion::new());
+    assert!(result.has_errors());
+    assert!(!result.is_lossless());
+
+    // The unexpected tokens are dropped
+    let result = parse_fallible("local x = = = =", LuaVersion::new());
+    assert!(!result.is_lossless());
+}
+
+#[test]
+#[cfg(feature = "luau")]
+fn test_parse_version() {
+    let luau_only = [
+        "local x: number = 1",
+        "while true do continue end",
+        "local x = if a then b else c",
+        "local x = 0b101",
+        "local x = 1_000",
+        "x += 1",
+    ];
+
+    for code in luau_only {
+        assert!(
+            full_moon::parse_version(code, LuaVersion::lua51()).is_err(),
+            "{code} should not parse as Lua 5.1"
+        );
+        assert!(
+            full_moon::parse_version(code, LuaVersion::luau()).is_ok(),
+            "{code} should parse as Luau"
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use full_moon::{ast, parse, parse_statement, print, tokenizer::Position};
+
+#[test]
+fn test_push_stmt() {
+    let mut ast = parse("local x = 1\nreturn x").unwrap();
+    let stmt = parse("local y = 2\n")
+        .unwrap()
+        .nodes()
+        .stmts()
+        .next()
+        .cloned()
+        .unwrap();
+
+    ast.nodes_mut().push_stmt(stmt, None);
+    assert_eq!(print(&ast), "local x = 1\nlocal y = 2\nreturn x");
+}
+
+#[test]
+fn test_insert_stmt() {
+    let mut ast = parse("local x = 1\nlocal z = 3\n").unwrap();
+    let (stmt, semicolon) = parse("local y = 2;\n")
+        .unwrap()
+        .nodes()
+        .stmts_with_semicolon()
+        .next()
+        .cloned()
+        .unwrap();
+
+    ast.nodes_mut().insert_stmt(1, stmt, semicolon);
+    assert_eq!(print(&ast), "local x = 1\nlocal y = 2;\nlocal z = 3\n");
+}
+
+#[test]
+fn test_remove_stmt() {
+    let mut ast = parse("local x = 1\nlocal y = 2\nreturn x").unwrap();
+
+    assert!(ast.nodes_mut().remove_stmt(2).is_none());
+
+    let (stmt, semicolon) = ast.nodes_mut().remove_stmt(1).unwrap();
+    assert_eq!(stmt.to_string(), "local y = 2\n");
+    assert!(semicolon.is_none());
+
+    assert_eq!(print(&ast), "local x = 1\nreturn x");
+}
+
+#[test]
+fn test_len() {
+    let ast = parse("").unwrap();
+    assert!(ast.nodes().is_empty());
+    assert_eq!(ast.nodes().len(), 0);
+
+    let ast = parse("local x = 1\ncall()").unwrap();
+    assert!(!ast.nodes().is_empty());
+    assert_eq!(ast.nodes().len(), 2);
+
+    let ast = parse("return").unwrap();
+    assert!(!ast.nodes().is_empty());
+    assert_eq!(ast.nodes().len(), 1);
+
+    let ast = parse("local x = 1\nreturn x").unwrap();
+    assert_eq!(ast.nodes().len(), 2);
+}
+
+fn first_stmt(code: &str) -> ast::Stmt {
+    parse(code)
+        .unwrap()
+        .nodes()
+        .stmts()
+        .next()
+        .cloned()
+        .unwrap()
+}
+
+#[test]
+fn test_mutable_accessors() {
+    let mut ast = parse("if a then\n    x()\nelse\n    y()\nend\nreturn z").unwrap();
+
+    let ast::Stmt::If(replacement) = first_stmt("if b then end") else {
+        panic!("expected an if statement");
+    };
+
+    let Some(ast::Stmt::If(if_stmt)) = ast.nodes_mut().stmts_mut().next() else {
+        panic!("expected an if statement");
+    };
+
+    *if_stmt.condition_mut() = replacement.condition().clone();
+    if_stmt
+        .else_block_mut()
+        .unwrap()
+        .push_stmt(first_stmt("    w()\n"), None);
+
+    assert!(ast.nodes_mut().last_stmt_mut().is_some());
+    assert_eq!(
+        print(&ast),
+        "if b then\n    x()\nelse\n    y()\n    w()\nend\nreturn z"
+    );
+}
+
+#[test]
+fn test_max_depth() {
+    let max_depth = |code| parse(code).unwrap().nodes().max_depth();
+
+    assert_eq!(max_depth(""), 0);
+    assert_eq!(max_depth("local x = 1"), 0);
+    assert_eq!(max_depth("do end"), 1);
+    assert_eq!(max_depth("if a then while b do end end"), 2);
+    assert_eq!(
+        max_depth("if a then elseif b then else for i = 1, 2 do repeat until c end end"),
+        3
+    );
+    assert_eq!(
+        max_depth("local function f() for _ in x do end end function g() end"),
+        2
+    );
+
+    // Anonymous functions count as well, wherever they are
+    assert_eq!(max_depth("local f = function() do end end"), 2);
+    assert_eq!(max_depth("foo(function() if a then end end)"), 2);
+    assert_eq!(max_depth("return function() while x do end end"), 2);
+    assert_eq!(max_depth("t = { f = function() end }"), 1);
+}
+
+#[test]
+fn test_statements() {
+    let ast = parse(
+        r#"
+        local function f(a)
+            if a then
+                print(a)
+            elseif b then
+                while true do
+                    break
+                end
+            else
+                for i = 1, 10 do
+                    do
+                        call(i)
+                    end
+                end
+            end
+
+            return a
+        end
+
+        repeat x = x + 1 until x > 10
+        local g = function() nested() end
+        M.h = function() assigned() end
+        call(function() callback() end)
+        return function() returned() end
+        "#,
+    )
+    .unwrap();
+
+    let statements: Vec<_> = ast
+        .statements()
+        .map(|stmt| stmt.to_string().trim().lines().next().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(
+        statements,
+        vec![
+            "local function f(a)",
+            "if a then",
+            "print(a)",
+            "while true do",
+            "for i = 1, 10 do",
+            "do",
+            "call(i)",
+            "repeat x = x + 1 until x > 10",
+            "x = x + 1",
+            "local g = function() nested() end",
+            "nested()",
+            "M.h = function() assigned() end",
+            "assigned()",
+            "call(function() callback() end)",
+            "callback()",
+            "returned()",
+        ]
+    );
+
+    assert_eq!(parse("").unwrap().statements().count(), 0);
+}
+
+#[test]
+fn test_stmt_predicates() {
+    let categories = |code: &str| {
+        let stmt = first_stmt(code);
+        (
+            stmt.is_declaration(),
+            stmt.is_loop(),
+            stmt.is_control_flow(),
+        )
+    };
+
+    assert_eq!(categories("local x = 1"), (true, false, false));
+    assert_eq!(categories("local function f() end"), (true, false, false));
+    assert_eq!(categories("function t.f() end"), (true, false, false));
+    assert_eq!(categories("x = 1"), (false, false, false));
+    assert_eq!(categories("call()"), (false, false, false));
+    assert_eq!(categories("do end"), (false, false, false));
+    assert_eq!(categories("while x do end"), (false, true, false));
+    assert_eq!(categories("repeat until x"), (false, true, false));
+    assert_eq!(categories("for i = 1, 2 do end"), (false, true, false));
+    assert_eq!(categories("for k, v in t do end"), (false, true, false));
+    assert_eq!(categories("if x then end"), (false, false, true));
+
+    #[cfg(feature = "luau")]
+    {
+        assert_eq!(categories("type T = number"), (true, false, false));
+        assert_eq!(categories("export type T = number"), (true, false, false));
+        assert_eq!(categories("x += 1"), (false, false, false));
+    }
+
+    #[cfg(feature = "lua52")]
+    {
+        assert_eq!(categories("goto label"), (false, false, true));
+        assert_eq!(categories("::label::"), (false, false, false));
+    }
+}
+
+#[test]
+fn test_last_stmt_accessors() {
+    let ast = parse("return 1, call()").unwrap();
+    let last_stmt = ast.nodes().last_stmt().unwrap();
+    assert!(last_stmt.is_return());
+    assert!(!last_stmt.is_break());
+    assert!(!last_stmt.is_continue());
+    assert_eq!(last_stmt.returns().unwrap().to_string(), "1, call()");
+
+    let ast = parse("return").unwrap();
+    let returns = ast.nodes().last_stmt().unwrap().returns();
+    assert!(returns.unwrap().is_empty());
+
+    let ast = parse("break").unwrap();
+    let last_stmt = ast.nodes().last_stmt().unwrap();
+    assert!(last_stmt.is_break());
+    assert!(!last_stmt.is_return());
+    assert!(last_stmt.returns().is_none());
+
+    #[cfg(feature = "luau")]
+    {
+        let ast = parse("continue").unwrap();
+        let last_stmt = ast.nodes().last_stmt().unwrap();
+        assert!(last_stmt.is_continue());
+        assert!(!last_stmt.is_break());
+        assert!(last_stmt.returns().is_none());
+    }
+}
+
+#[test]
+fn test_parse_statement() {
+    let stmt = parse_statement("local x = call(1)\n").unwrap();
+    assert_eq!(stmt.to_string(), "local x = call(1)\n");
+    assert!(matches!(stmt, ast::Stmt::LocalAssignment(_)));
+
+    let stmt = parse_statement("if x then return end").unwrap();
+    assert!(stmt.is_control_flow());
+
+    assert!(parse_statement("").is_err());
+    assert!(parse_statement("1 + 2").is_err());
+    assert!(parse_statement("return 1").is_err());
+    assert!(parse_statement("break").is_err());
+    assert!(parse_statement("local x = 1;").is_err());
+
+    let errors = parse_statement("x = 1 y = 2").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].range().0.bytes(), 6);
+}
+
+#[test]
+fn test_body_ranges() {
+    let code = "do a() end\nwhile x do b() end\nfor i = 1, 2 do c() end\nfor k in t do d() end\n\
+        repeat e() until y\nlocal function f(z) g() end\nif p then h() elseif q then i() else j() end";
+    let ast = parse(code).unwrap();
+    let body = |(start, end): (Position, Position)| &code[start.bytes()..end.bytes()];
+
+    let bodies = ast
+        .nodes()
+        .stmts()
+        .flat_map(|stmt| match stmt {
+            ast::Stmt::Do(do_block) => vec![body(do_block.body_range().unwrap())],
+            ast::Stmt::While(while_loop) => vec![body(while_loop.body_range().unwrap())],
+            ast::Stmt::NumericFor(numeric_for) => vec![body(numeric_for.body_range().unwrap())],
+            ast::Stmt::GenericFor(generic_for) => vec![body(generic_for.body_range().unwrap())],
+            ast::Stmt::Repeat(repeat) => vec![body(repeat.body_range().unwrap())],
+            ast::Stmt::LocalFunction(function) => vec![body(function.body().body_range().unwrap())],
+            ast::Stmt::If(if_stmt) => if_stmt
+                .body_ranges()
+                .unwrap()
+                .into_iter()
+                .map(body)
+                .collect(),
+            _ => panic!("unexpected statement {stmt}"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        bodies,
+        vec![" a() ", " b() ", " c() ", " d() ", " e() ", " g() ", " h() ", " i() ", " j() "]
+    );
+}
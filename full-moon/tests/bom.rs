@@ -0,0 +1,77 @@
+use full_moon::{
+    ast::LuaVersion,
+    parse, print,
+    tokenizer::{Lexer, LexerResult, TokenType},
+};
+
+#[test]
+fn test_bom() {
+    let code = String::from_utf8(b"\xEF\xBB\xBFlocal x = 1\nprint(x)".to_vec()).unwrap();
+    let ast = parse(&code).unwrap();
+
+    assert!(ast.had_bom());
+    assert_eq!(print(&ast), code);
+    assert_eq!(ast.nodes().stmts().count(), 2);
+
+    let first_token = ast.tokens().next().unwrap();
+    assert_eq!(first_token.token().to_string(), "local");
+    assert_eq!(first_token.token().start_position().bytes(), 3);
+
+    let bom = first_token.leading_trivia().next().unwrap();
+    assert!(bom.is_bom());
+    assert_eq!(
+        bom.token_type(),
+        &TokenType::Whitespace {
+            characters: "\u{feff}".into()
+        }
+    );
+}
+
+#[test]
+fn test_no_bom() {
+    let ast = parse("local x = 1").unwrap();
+    assert!(!ast.had_bom());
+
+    let ast = parse("").unwrap();
+    assert!(!ast.had_bom());
+}
+
+#[test]
+fn test_bom_only() {
+    let ast = parse("\u{feff}").unwrap();
+    assert!(ast.had_bom());
+    assert_eq!(print(&ast), "\u{feff}");
+}
+
+#[test]
+fn test_bom_with_shebang() {
+    let code = "\u{feff}#!/usr/bin/env lua\nprint(1)";
+    let ast = parse(code).unwrap();
+
+    assert!(ast.had_bom());
+    assert_eq!(ast.shebang().unwrap().to_string(), "#!/usr/bin/env lua");
+    assert_eq!(print(&ast), code);
+
+    let ast = ast.without_shebang();
+    assert!(ast.had_bom());
+    assert_eq!(print(&ast), "\u{feff}print(1)");
+}
+
+#[test]
+fn test_bom_not_at_start() {
+    assert!(parse("local x = 1 \u{feff}").is_err());
+}
+
+#[test]
+fn test_bom_before_recovered_token() {
+    let lexer = Lexer::new("\u{feff}#!/usr/bin/env lua\n\"unclosed", LuaVersion::new());
+
+    let Some(LexerResult::Recovered(token, _)) = lexer.current() else {
+        panic!("expected a recovered token");
+    };
+
+    let leading_trivia = token.leading_trivia().collect::<Vec<_>>();
+    assert_eq!(leading_trivia.len(), 3);
+    assert!(leading_trivia[0].is_bom());
+    assert_eq!(leading_trivia[1].to_string(), "#!/usr/bin/env lua");
+}
@@ -0,0 +1,66 @@
+use full_moon::{
+    ast::{Expression, Stmt, Var},
+    build,
+    node::Node,
+    parse,
+    tokenizer::{StringLiteralQuoteType, TokenReference},
+};
+
+fn name(name: &str) -> Expression {
+    Expression::Var(Var::Name(TokenReference::identifier(name)))
+}
+
+#[test]
+fn test_build_statements() {
+    assert_eq!(
+        build::local(
+            "greeting",
+            Expression::string("hi", StringLiteralQuoteType::Double)
+        )
+        .to_string(),
+        "local greeting = \"hi\""
+    );
+
+    assert_eq!(
+        build::assign(
+            vec![Var::Name(TokenReference::identifier("x"))],
+            vec![Expression::number("1")]
+        )
+        .to_string(),
+        "x = 1"
+    );
+
+    assert_eq!(build::call("f", Vec::new()).to_string(), "f()");
+    assert_eq!(
+        build::call("f", vec![name("a"), name("b"), name("c")]).to_string(),
+        "f(a, b, c)"
+    );
+
+    assert_eq!(build::block(Vec::new()).to_string(), "");
+}
+
+#[test]
+fn test_build_chunk() {
+    let chunk = build::block(vec![
+        Stmt::LocalAssignment(build::local("count", Expression::number("0"))),
+        Stmt::If(build::if_then(
+            name("enabled"),
+            build::block(vec![
+                Stmt::Assignment(build::assign(
+                    vec![Var::Name(TokenReference::identifier("count"))],
+                    vec![Expression::number("1")],
+                )),
+                Stmt::FunctionCall(build::call("print", vec![name("count")])),
+            ]),
+        )),
+    ]);
+
+    let code = chunk.to_string();
+    assert_eq!(
+        code,
+        "local count = 0\nif enabled then\ncount = 1\nprint(count)\nend"
+    );
+
+    let ast = parse(&code).unwrap();
+    assert!(ast.nodes().similar(&chunk));
+}
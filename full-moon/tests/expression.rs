@@ -0,0 +1,175 @@
+use full_moon::{ast, parse, parse_expression};
+
+fn values(code: &str) -> Vec<ast::Expression> {
+    let ast = parse(code).unwrap();
+    let Some(ast::Stmt::LocalAssignment(local_assignment)) = ast.nodes().stmts().next() else {
+        panic!("expected a local assignment");
+    };
+
+    local_assignment.expressions().iter().cloned().collect()
+}
+
+#[test]
+fn test_literal_values() {
+    let values =
+        values("local _ = 1.5, ((0xFF)), 'a\\nb', ([[raw\\n]]), true, (false), nil, -1, x, 1 + 2");
+
+    assert_eq!(values[0].as_number(), Some(1.5));
+    assert_eq!(values[1].as_number(), Some(255.0));
+    assert_eq!(values[2].as_string().as_deref(), Some("a\nb"));
+    assert_eq!(values[3].as_string().as_deref(), Some("raw\\n"));
+    assert_eq!(values[4].as_bool(), Some(true));
+    assert_eq!(values[5].as_bool(), Some(false));
+
+    for value in &values[6..] {
+        assert_eq!(value.as_number(), None);
+        assert_eq!(value.as_string(), None);
+        assert_eq!(value.as_bool(), None);
+    }
+
+    assert_eq!(values[0].as_string(), None);
+    assert_eq!(values[2].as_number(), None);
+    assert_eq!(values[4].as_number(), None);
+}
+
+#[test]
+fn test_const_truthiness() {
+    let values = values(
+        "local _ = nil, (false), true, 0, \"\", ((-0)), {}, function() end, x, ..., f(), not nil, a or b",
+    );
+    let truthiness = values
+        .iter()
+        .map(ast::Expression::const_truthiness)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        truthiness,
+        vec![
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "luau")]
+fn test_interpolated_string_parts() {
+    use full_moon::ast::types::InterpolatedPart;
+
+    let values = values("local _ = `a {x} b {y + 1}`, `plain`");
+    let ast::Expression::InterpolatedString(interpolated) = &values[0] else {
+        panic!("expected an interpolated string");
+    };
+
+    let parts = interpolated
+        .parts()
+        .map(|part| match part {
+            InterpolatedPart::Literal(literal) => format!("literal {:?}", literal.as_str()),
+            InterpolatedPart::Expression(expression) => format!("expression {expression}"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        parts,
+        vec![
+            "literal \"a \"",
+            "expression x",
+            "literal \" b \"",
+            "expression y + 1",
+            "literal \"\"",
+        ]
+    );
+
+    let ast::Expression::InterpolatedString(plain) = &values[1] else {
+        panic!("expected an interpolated string");
+    };
+
+    let plain_parts = plain.parts().collect::<Vec<_>>();
+    assert!(matches!(
+        plain_parts.as_slice(),
+        [InterpolatedPart::Literal(literal)] if literal.as_str() == "plain"
+    ));
+}
+
+#[test]
+#[cfg(feature = "luau")]
+fn test_interpolated_string_from_parts() {
+    use full_moon::{
+        ast::types::{InterpolatedPart, InterpolatedString},
+        tokenizer::{InterpolatedStringKind, TokenType},
+    };
+
+    let kinds = |string: &InterpolatedString| {
+        string
+            .segments()
+            .map(|segment| &segment.literal)
+            .chain(std::iter::once(string.last_string()))
+            .map(|token| match token.token_type() {
+                TokenType::InterpolatedString { kind, .. } => *kind,
+                _ => panic!("expected an interpolated string token"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let simple =
+        InterpolatedString::from_parts(vec![InterpolatedPart::Literal("plain".to_owned())]);
+    assert_eq!(simple.to_string(), "`plain`");
+    assert_eq!(kinds(&simple), vec![InterpolatedStringKind::Simple]);
+
+    let mut numbers = values("local _ = 1, 2").into_iter();
+    let string = InterpolatedString::from_parts(vec![
+        InterpolatedPart::Literal("hello ".to_owned()),
+        InterpolatedPart::Literal("world".to_owned()),
+        InterpolatedPart::Expression(numbers.next().unwrap()),
+        InterpolatedPart::Expression(numbers.next().unwrap()),
+    ]);
+    assert_eq!(string.to_string(), "`hello world{1}{2}`");
+    assert_eq!(
+        kinds(&string),
+        vec![
+            InterpolatedStringKind::Begin,
+            InterpolatedStringKind::Middle,
+            InterpolatedStringKind::End,
+        ]
+    );
+
+    // Building from parts undoes `parts`
+    let parsed_values = values("local _ = `a {x} b {y}`");
+    let ast::Expression::InterpolatedString(parsed) = &parsed_values[0] else {
+        panic!("expected an interpolated string");
+    };
+    assert_eq!(
+        InterpolatedString::from_parts(parsed.parts().collect()).to_string(),
+        parsed.to_string()
+    );
+}
+
+#[test]
+fn test_parse_expression() {
+    let expression = parse_expression("  1 + 2 * call(x) -- comment").unwrap();
+    assert_eq!(expression.to_string(), "  1 + 2 * call(x) -- comment");
+    assert!(matches!(expression, ast::Expression::BinaryOperator { .. }));
+
+    let expression = parse_expression("function(a) return a end").unwrap();
+    assert!(matches!(expression, ast::Expression::Function(_)));
+
+    assert!(parse_expression("").is_err());
+    assert!(parse_expression("local x = 1").is_err());
+    assert!(parse_expression("1 + ").is_err());
+    assert!(parse_expression("\"unclosed").is_err());
+
+    let errors = parse_expression("1 + 2 3").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].range().0.bytes(), 6);
+}
@@ -0,0 +1,118 @@
+use full_moon::{ast, parse};
+
+fn first_function_body(ast: &ast::Ast) -> &ast::FunctionBody {
+    match ast.nodes().stmts().next() {
+        Some(ast::Stmt::LocalFunction(local_function)) => local_function.body(),
+        _ => panic!("expected a local function"),
+    }
+}
+
+#[test]
+fn test_parameter_accessors() {
+    let ast = parse("local function f(a, b, ...) end").unwrap();
+    let parameters = first_function_body(&ast)
+        .parameters()
+        .iter()
+        .map(|parameter| (parameter.token().token().to_string(), parameter.is_vararg()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        parameters,
+        vec![
+            ("a".to_owned(), false),
+            ("b".to_owned(), false),
+            ("...".to_owned(), true),
+        ]
+    );
+}
+
+#[test]
+fn test_arity() {
+    let arity = |code: &str| {
+        let ast = parse(code).unwrap();
+        let body = first_function_body(&ast);
+        let names = body
+            .named_parameters()
+            .map(|name| name.token().to_string())
+            .collect::<Vec<_>>();
+
+        (body.parameter_count(), body.is_variadic(), names)
+    };
+
+    assert_eq!(arity("local function f() end"), (0, false, vec![]));
+    assert_eq!(arity("local function f(...) end"), (0, true, vec![]));
+    assert_eq!(
+        arity("local function f(a, b) end"),
+        (2, false, vec!["a".to_owned(), "b".to_owned()])
+    );
+    assert_eq!(
+        arity("local function f(a, ...) end"),
+        (1, true, vec!["a".to_owned()])
+    );
+}
+
+#[test]
+fn test_cyclomatic_complexity() {
+    let complexity = |code: &str| {
+        let ast = parse(code).unwrap();
+        first_function_body(&ast).cyclomatic_complexity()
+    };
+
+    assert_eq!(complexity("local function f() end"), 1);
+
+    assert_eq!(
+        complexity(
+            r#"
+            local function f(a, b)
+                if a and b then
+                    print("both")
+                end
+
+                if a then
+                    print("a")
+                end
+            end
+            "#
+        ),
+        4
+    );
+
+    assert_eq!(
+        complexity(
+            r#"
+            local function f(list)
+                for _, value in ipairs(list) do
+                    if value > 1 then
+                    elseif value < 0 or value == 0 then
+                    end
+                end
+
+                while true do end
+                repeat until true
+                for i = 1, 10 do end
+            end
+            "#
+        ),
+        8
+    );
+
+    // Nested functions are not counted
+    assert_eq!(
+        complexity(
+            r#"
+            local function f(a)
+                local g = function()
+                    if a then end
+                end
+
+                local function h()
+                    return a or b
+                end
+
+                return a and g
+            end
+            "#
+        ),
+        2
+    );
+}
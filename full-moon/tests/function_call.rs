@@ -0,0 +1,117 @@
+use full_moon::{ast, parse};
+
+fn called_name(code: &str) -> Option<String> {
+    let ast = parse(code).unwrap();
+
+    match ast.nodes().stmts().next() {
+        Some(ast::Stmt::FunctionCall(call)) => call.called_name(),
+        _ => panic!("expected a function call"),
+    }
+}
+
+#[test]
+fn test_called_name() {
+    assert_eq!(called_name("foo()").as_deref(), Some("foo"));
+    assert_eq!(called_name("a.b.c()").as_deref(), Some("a.b.c"));
+    assert_eq!(called_name("obj:method()").as_deref(), Some("obj:method"));
+    assert_eq!(called_name("a.b:c 'x'").as_deref(), Some("a.b:c"));
+    assert_eq!(
+        called_name("  spaced . name ( )").as_deref(),
+        Some("spaced.name")
+    );
+}
+
+#[test]
+fn test_called_name_complex() {
+    assert_eq!(called_name("(foo)()"), None);
+    assert_eq!(called_name("a[1]()"), None);
+    assert_eq!(called_name("a['b']()"), None);
+    assert_eq!(called_name("foo()()"), None);
+    assert_eq!(called_name("foo():bar()"), None);
+}
+
+#[test]
+fn test_contained_span_bounds() {
+    let ast = parse("call(\n\ta,\n\tb\n    )").unwrap();
+    let Some(ast::Stmt::FunctionCall(call)) = ast.nodes().stmts().next() else {
+        panic!("expected a function call");
+    };
+
+    let Some(ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+        parentheses,
+        ..
+    }))) = call.suffixes().next()
+    else {
+        panic!("expected parentheses");
+    };
+
+    assert_eq!(parentheses.open().to_string(), "(\n");
+    assert_eq!(parentheses.close().to_string(), "    )");
+    assert_eq!(
+        parentheses.tokens(),
+        (parentheses.open(), parentheses.close())
+    );
+
+    let close = parentheses.close().with_leading_trivia(Vec::new());
+    let parentheses = parentheses.clone().with_close(close);
+    assert_eq!(parentheses.open().to_string(), "(\n");
+    assert_eq!(parentheses.close().to_string(), ")");
+
+    let open = parentheses.open().with_trailing_trivia(Vec::new());
+    let parentheses = parentheses.with_open(open);
+    assert_eq!(parentheses.open().to_string(), "(");
+    assert_eq!(parentheses.close().to_string(), ")");
+}
+
+#[test]
+fn test_method_calls() {
+    let ast = parse("x:y().z(1):w 'a'").unwrap();
+    let Some(ast::Stmt::FunctionCall(call)) = ast.nodes().stmts().next() else {
+        panic!("expected a function call");
+    };
+
+    let suffixes: Vec<_> = call.suffixes().collect();
+    assert_eq!(suffixes.len(), 4);
+
+    let method_names: Vec<_> = suffixes
+        .iter()
+        .map(|suffix| {
+            suffix
+                .as_method_call()
+                .and_then(ast::MethodCall::method_name)
+        })
+        .collect();
+    assert_eq!(method_names, vec![Some("y"), None, None, Some("w")]);
+
+    let is_method_call: Vec<_> = suffixes
+        .iter()
+        .filter_map(|suffix| match suffix {
+            ast::Suffix::Call(call) => Some(call.is_method_call()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(is_method_call, vec![true, false, true]);
+}
+
+#[test]
+fn test_first_argument() {
+    fn first_argument(code: &str) -> Option<String> {
+        let ast = parse(code).unwrap();
+        let Some(ast::Stmt::FunctionCall(call)) = ast.nodes().stmts().next() else {
+            panic!("expected a function call");
+        };
+
+        let Some(ast::Suffix::Call(ast::Call::AnonymousCall(args))) = call.suffixes().next() else {
+            panic!("expected an anonymous call");
+        };
+
+        args.first_argument().map(|argument| argument.to_string())
+    }
+
+    assert_eq!(first_argument("f(1, 2)").as_deref(), Some("1"));
+    assert_eq!(first_argument("f()"), None);
+
+    // String and table calls pass a single argument
+    assert_eq!(first_argument("f \"x\"").as_deref(), Some("\"x\""));
+    assert_eq!(first_argument("f {1}").as_deref(), Some("{1}"));
+}
@@ -0,0 +1,66 @@
+use full_moon::{ast, parse};
+
+fn function_name(code: &str) -> ast::FunctionName {
+    let ast = parse(code).unwrap();
+
+    match ast.nodes().stmts().next() {
+        Some(ast::Stmt::FunctionDeclaration(declaration)) => declaration.name().clone(),
+        _ => panic!("expected a function declaration"),
+    }
+}
+
+#[test]
+fn test_to_path() {
+    assert_eq!(function_name("function f() end").to_path(), "f");
+    assert_eq!(function_name("function a.b.c() end").to_path(), "a.b.c");
+    assert_eq!(
+        function_name("function a . b --[[ comment ]] . c : d() end").to_path(),
+        "a.b.c:d"
+    );
+}
+
+#[test]
+fn test_from_path() {
+    for path in ["f", "a.b.c", "a:b", "_a.b2.C:_d"] {
+        let name = ast::FunctionName::from_path(path).unwrap();
+        assert_eq!(name.to_path(), path);
+        assert_eq!(name.to_string(), path);
+    }
+
+    let name = ast::FunctionName::from_path("a.b:c").unwrap();
+    assert_eq!(name.names().len(), 2);
+    assert_eq!(name.method_name().unwrap().to_string(), "c");
+
+    let code = ast::FunctionDeclaration::new(name).to_string();
+    assert!(code.starts_with("function a.b:c("));
+    assert_eq!(function_name(&code).to_path(), "a.b:c");
+}
+
+#[test]
+fn test_from_path_malformed() {
+    for path in [
+        "", ".", "a.", ".a", "a..b", "a:", ":a", "a:b:c", "a:b.c", "a.b c", "1a", "a-b", "end",
+        "a.local", "a:nil", "a::b",
+    ] {
+        assert!(
+            ast::FunctionName::from_path(path).is_none(),
+            "{path:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_from_path_version_keywords() {
+    // `goto` is only a keyword from Lua 5.2 onwards
+    assert_eq!(
+        ast::FunctionName::from_path("goto").is_none(),
+        cfg!(feature = "lua52")
+    );
+    assert_eq!(
+        ast::FunctionName::from_path("a:goto").is_none(),
+        cfg!(feature = "lua52")
+    );
+
+    assert!(ast::FunctionName::from_path("é").is_none());
+    assert!(ast::FunctionName::from_path("aé").is_none());
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "lua52")]
+use full_moon::parse;
+
+fn resolved(code: &str) -> Vec<(String, bool)> {
+    parse(code)
+        .unwrap()
+        .resolve_gotos()
+        .iter()
+        .map(|resolution| {
+            (
+                resolution.goto().label_name().token().to_string(),
+                resolution.is_resolved(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_resolve_gotos() {
+    let code = "goto done\nprint(1)\n::done::\ngoto missing";
+    let resolutions = parse(code).unwrap().resolve_gotos();
+
+    assert_eq!(resolutions.len(), 2);
+    assert_eq!(
+        resolutions[0]
+            .label()
+            .map(|label| label.name().token().to_string()),
+        Some("done".to_owned())
+    );
+    assert!(!resolutions[1].is_resolved());
+    assert!(resolutions[1].label().is_none());
+}
+
+#[test]
+fn test_resolve_gotos_scopes() {
+    // Labels are visible in nested blocks, including backwards jumps
+    assert_eq!(
+        resolved("::top:: while true do if x then goto top end goto continue ::continue:: end"),
+        vec![("top".to_owned(), true), ("continue".to_owned(), true)]
+    );
+
+    // Labels inside of a nested block are not visible outside of it
+    assert_eq!(
+        resolved("do ::inner:: end goto inner"),
+        vec![("inner".to_owned(), false)]
+    );
+
+    // Labels are not visible inside of nested functions
+    assert_eq!(
+        resolved("::outer:: local f = function() goto outer end"),
+        vec![("outer".to_owned(), false)]
+    );
+}
@@ -0,0 +1,69 @@
+use full_moon::{ast, parse};
+
+fn first_if(ast: &ast::Ast) -> &ast::If {
+    match ast.nodes().stmts().next() {
+        Some(ast::Stmt::If(if_stmt)) => if_stmt,
+        _ => panic!("expected an if statement"),
+    }
+}
+
+#[test]
+fn test_branches() {
+    let ast = parse("if a then x() elseif b then y() else z() end").unwrap();
+    let branches = first_if(&ast)
+        .branches()
+        .map(|(condition, block)| {
+            (
+                condition.map(|condition| condition.to_string().trim().to_owned()),
+                block.to_string().trim().to_owned(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        branches,
+        vec![
+            (Some("a".to_owned()), "x()".to_owned()),
+            (Some("b".to_owned()), "y()".to_owned()),
+            (None, "z()".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_branches_without_else() {
+    let ast = parse("if a then elseif b then elseif c then end").unwrap();
+    let branches = first_if(&ast).branches().collect::<Vec<_>>();
+    assert_eq!(branches.len(), 3);
+    assert!(branches.iter().all(|(condition, _)| condition.is_some()));
+
+    let ast = parse("if a then end").unwrap();
+    assert_eq!(first_if(&ast).branches().count(), 1);
+}
+
+#[test]
+fn test_has_condition() {
+    use ast::HasCondition;
+
+    let ast = parse("if a then elseif b then end while c do end repeat until d").unwrap();
+    let stmts = ast.nodes().stmts().collect::<Vec<_>>();
+
+    let ast::Stmt::If(if_stmt) = stmts[0] else {
+        panic!("expected an if statement");
+    };
+    assert_eq!(HasCondition::condition(if_stmt).to_string().trim(), "a");
+
+    let else_if = &if_stmt.else_if().unwrap()[0];
+    assert_eq!(HasCondition::condition(else_if).to_string().trim(), "b");
+
+    let ast::Stmt::While(while_loop) = stmts[1] else {
+        panic!("expected a while loop");
+    };
+    assert_eq!(HasCondition::condition(while_loop).to_string().trim(), "c");
+
+    let ast::Stmt::Repeat(repeat) = stmts[2] else {
+        panic!("expected a repeat loop");
+    };
+    assert_eq!(repeat.condition(), repeat.until());
+    assert_eq!(repeat.condition().to_string().trim(), "d");
+}
@@ -1,4 +1,9 @@
-use full_moon::{node::Node, parse};
+use full_moon::{
+    ast,
+    node::{fold_ast, Descendant, Node},
+    parse,
+    tokenizer::TokenType,
+};
 
 #[test]
 fn surrounding_trivia() {
@@ -13,6 +18,22 @@ fn surrounding_trivia() {
     assert_eq!(prev.next(), None);
 }
 
+#[test]
+fn test_trivia_text() {
+    let ast = parse("--- Adds two numbers\n--- Returns the sum\nlocal function add(a, b) end -- done\nreturn add").unwrap();
+    let stmt = ast.nodes().stmts().next().unwrap();
+
+    assert_eq!(
+        stmt.leading_trivia_text(),
+        "--- Adds two numbers\n--- Returns the sum\n"
+    );
+    assert_eq!(stmt.trailing_trivia_text(), " -- done\n");
+
+    let last_stmt = ast.nodes().last_stmt().unwrap();
+    assert_eq!(last_stmt.leading_trivia_text(), "");
+    assert_eq!(last_stmt.trailing_trivia_text(), "");
+}
+
 #[test]
 fn test_similar() {
     let ast = parse("local x = 1; --[[ uh oh, filler ]] local x = 1; local x = 2;").unwrap();
@@ -36,3 +57,227 @@ fn test_tokens_back() {
     let mut tokens = source.nodes().tokens();
     assert_eq!(tokens.next_back().unwrap().to_string(), "1");
 }
+
+#[test]
+fn test_ast_tokens() {
+    let source = parse("local abcd = 1").unwrap();
+    let tokens = source.tokens().collect::<Vec<_>>();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens.last().unwrap().token_type(), &TokenType::Eof);
+
+    let mut reversed = source.tokens().rev().collect::<Vec<_>>();
+    reversed.reverse();
+    assert_eq!(tokens, reversed);
+}
+
+#[test]
+fn test_comments() {
+    let ast = parse(
+        "-- header\n--[[ block ]] local x = 1 -- trailing\n--[==[\nlong\n]==]\nlocal y = 2\n-- end",
+    )
+    .unwrap();
+
+    let comments = ast.comments().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        comments,
+        vec![
+            "-- header",
+            "--[[ block ]]",
+            "-- trailing",
+            "--[==[\nlong\n]==]",
+            "-- end",
+        ]
+    );
+
+    let stmts = ast.nodes().stmts().collect::<Vec<_>>();
+
+    let leading = stmts[0].leading_comments();
+    assert_eq!(leading.len(), 2);
+    assert_eq!(leading[0].to_string(), "-- header");
+    assert_eq!(leading[1].to_string(), "--[[ block ]]");
+
+    let trailing = stmts[0].trailing_comments();
+    assert_eq!(trailing.len(), 1);
+    assert_eq!(trailing[0].to_string(), "-- trailing");
+
+    let leading = stmts[1].leading_comments();
+    assert_eq!(leading.len(), 1);
+    assert_eq!(leading[0].to_string(), "--[==[\nlong\n]==]");
+    assert!(stmts[1].trailing_comments().is_empty());
+}
+
+#[test]
+fn test_text() {
+    let ast =
+        parse("x =   foo . bar -- comment\nlocal function f()\n    -- inside\n    return 1\nend\n")
+            .unwrap();
+    let stmts = ast.nodes().stmts().collect::<Vec<_>>();
+
+    let ast::Stmt::Assignment(assignment) = stmts[0] else {
+        panic!("expected an assignment");
+    };
+
+    let value = assignment.expressions().iter().next().unwrap();
+    assert_eq!(value.text(), "foo . bar");
+    assert_ne!(value.to_string(), value.text());
+
+    assert_eq!(
+        stmts[1].text(),
+        "local function f()\n    -- inside\n    return 1\nend"
+    );
+}
+
+#[test]
+fn test_ast_similar() {
+    let ast = parse("-- comment\nlocal x = { 1, 2 }\n").unwrap();
+    assert!(ast.similar(&parse("local x={1,2}").unwrap()));
+    assert!(ast.similar(&parse("local x = {1, 2} -- different comment").unwrap()));
+    assert!(!ast.similar(&parse("local x = {1, 2, 3}").unwrap()));
+    assert!(!ast.similar(&parse("local y = {1, 2}").unwrap()));
+}
+
+#[test]
+fn test_contains_position() {
+    let ast = parse("local x = 1\nlocal y = 2").unwrap();
+    let stmts = ast.nodes().stmts().collect::<Vec<_>>();
+
+    let (start, end) = stmts[0].range().unwrap();
+    assert!(stmts[0].contains_position(start));
+    assert!(stmts[0].contains_position(end));
+    assert!(!stmts[1].contains_position(start));
+    assert!(!stmts[0].contains_position(stmts[1].start_position().unwrap()));
+}
+
+#[test]
+fn test_descendants() {
+    let ast = parse("local function f(a) if a then return b + c end end").unwrap();
+    let Some(ast::Stmt::LocalFunction(local_function)) = ast.nodes().stmts().next() else {
+        panic!("expected a local function");
+    };
+
+    let identifiers = local_function
+        .body()
+        .descendants()
+        .filter_map(|descendant| match descendant {
+            Descendant::TokenReference(token) => match token.token_type() {
+                TokenType::Identifier { identifier } => Some(identifier.to_string()),
+                _ => None,
+            },
+            Descendant::Node(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(identifiers, vec!["a", "a", "b", "c"]);
+
+    // Token references are yielded in the same order as `tokens`
+    let tokens = local_function
+        .descendants()
+        .filter_map(|descendant| match descendant {
+            Descendant::TokenReference(token) => Some(token),
+            Descendant::Node(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, local_function.tokens().collect::<Vec<_>>());
+
+    // Nodes come before their own descendants
+    let Some(Descendant::Node(first)) = ast.nodes().descendants().next() else {
+        panic!("expected a node");
+    };
+
+    assert_eq!(first.start_position(), local_function.start_position());
+}
+
+#[test]
+fn test_fold_ast() {
+    let ast = parse("local x = 1 -- comment\nprint(x)").unwrap();
+
+    let (nodes, tokens) = fold_ast(
+        &ast,
+        (0, 0),
+        |(nodes, tokens), descendant| match descendant {
+            Descendant::Node(_) => (nodes + 1, tokens),
+            Descendant::TokenReference(_) => (nodes, tokens + 1),
+        },
+    );
+
+    assert!(nodes > 0);
+    // Includes the EOF token, unlike `Node::tokens`
+    assert_eq!(tokens, ast.nodes().tokens().count() + 1);
+
+    // The root block comes first, and the EOF token comes last
+    let first = fold_ast(&ast, None, |first, descendant| first.or(Some(descendant)));
+    let Some(Descendant::Node(first)) = first else {
+        panic!("expected a node");
+    };
+    assert_eq!(first.tokens().count(), ast.nodes().tokens().count());
+
+    let last = fold_ast(&ast, None, |_, descendant| Some(descendant));
+    assert!(matches!(
+        last,
+        Some(Descendant::TokenReference(token)) if *token.token_type() == TokenType::Eof
+    ));
+}
+
+#[test]
+fn test_stable_hash() {
+    let first_stmt_hash = |code| {
+        let ast = parse(code).unwrap();
+        let stmt = ast.nodes().stmts().next().unwrap();
+        stmt.stable_hash()
+    };
+
+    let original = parse("local x = 1 + 2").unwrap();
+    let reformatted = parse("-- comment\nlocal   x=1+\n\t2 -- trailing\n").unwrap();
+    assert!(original.similar(&reformatted));
+    assert_eq!(original.stable_hash(), reformatted.stable_hash());
+
+    assert_eq!(
+        first_stmt_hash("local x = 1 + 2"),
+        first_stmt_hash("\n\nlocal x = 1+2 print(x)")
+    );
+    assert_ne!(
+        first_stmt_hash("local x = 1 + 2"),
+        first_stmt_hash("local x = 1 + 3")
+    );
+    assert_ne!(
+        first_stmt_hash("local x = 'a'"),
+        first_stmt_hash("local x = \"a\"")
+    );
+}
+
+#[test]
+fn test_string_literals() {
+    let ast =
+        parse("local a = 'one'\nprint(\"t\\119o\", [[three]], a.b, 4)\nlocal t = { four = 4 }")
+            .unwrap();
+
+    let strings = ast
+        .string_literals()
+        .map(|token| {
+            (
+                token.string_value().unwrap().into_owned(),
+                token.start_position().line(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        strings,
+        vec![
+            ("one".to_owned(), 1),
+            ("two".to_owned(), 2),
+            ("three".to_owned(), 2),
+        ]
+    );
+
+    #[cfg(feature = "luau")]
+    {
+        // Interpolated strings are only included when they have no expressions in them
+        let ast = parse("local _ = `simple \\{x}`, `hello {name}!`, `a {b} c {d} e`").unwrap();
+        let strings = ast
+            .string_literals()
+            .map(|token| token.string_value().unwrap().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(strings, vec!["simple {x}"]);
+    }
+}
@@ -0,0 +1,227 @@
+use full_moon::{
+    ast::{self, span},
+    node::{Descendant, Node},
+    parse,
+    tokenizer::Position,
+};
+
+#[test]
+fn test_position_at_byte() {
+    let ast = parse("local s = \"héllo\" -- ñ\nlocal y = 1").unwrap();
+
+    let position = ast.position_at_byte(11).unwrap();
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (11, 1, 12)
+    );
+
+    // Both bytes of `é` belong to the same character
+    for byte in [12, 13] {
+        let position = ast.position_at_byte(byte).unwrap();
+        assert_eq!(
+            (position.bytes(), position.line(), position.character()),
+            (12, 1, 13)
+        );
+    }
+
+    let position = ast.position_at_byte(14).unwrap();
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (14, 1, 14)
+    );
+
+    // `ñ` in the comment, after which `\n` and `local` follow
+    let position = ast.position_at_byte(23).unwrap();
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (22, 1, 22)
+    );
+
+    let position = ast.position_at_byte(25).unwrap();
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (25, 2, 1)
+    );
+
+    assert!(ast.position_at_byte(36).is_some());
+    assert_eq!(ast.position_at_byte(37), None);
+}
+
+#[test]
+fn test_position_at_byte_trailing_comment() {
+    let ast = parse("local x = 1\n-- c").unwrap();
+
+    // Inside of the comment before the EOF token
+    let position = ast.position_at_byte(13).unwrap();
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (13, 2, 2)
+    );
+
+    let position = ast.position_at_byte(16).unwrap();
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (16, 2, 5)
+    );
+
+    assert_eq!(ast.position_at_byte(17), None);
+}
+
+#[test]
+fn test_position_advance() {
+    let start = Position {
+        bytes: 0,
+        line: 1,
+        character: 1,
+    };
+
+    let position = start.advance("a\nbc");
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (4, 2, 3)
+    );
+
+    let position = start.advance("a\n");
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (2, 2, 1)
+    );
+
+    let position = start.advance("é");
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (2, 1, 2)
+    );
+
+    assert_eq!(start.advance(""), start);
+}
+
+#[test]
+fn test_position_with_offset() {
+    let start = Position {
+        bytes: 10,
+        line: 2,
+        character: 3,
+    };
+
+    let position = start.with_offset(4, 1, 2);
+    assert_eq!(
+        (position.bytes(), position.line(), position.character()),
+        (14, 3, 5)
+    );
+
+    assert_eq!(start.with_offset(0, 0, 0), start);
+}
+
+#[test]
+fn test_node_at() {
+    let ast = parse("-- comment\nlocal x   = foo.bar").unwrap();
+    let at_byte = |byte| ast.node_at(ast.position_at_byte(byte).unwrap());
+
+    // Inside an identifier
+    let Some(Descendant::TokenReference(token)) = at_byte(17) else {
+        panic!("expected a token");
+    };
+    assert_eq!(token.text(), "x");
+
+    let Some(Descendant::TokenReference(token)) = at_byte(28) else {
+        panic!("expected a token");
+    };
+    assert_eq!(token.text(), "bar");
+
+    // Where one token ends and the next begins
+    let Some(Descendant::TokenReference(token)) = at_byte(26) else {
+        panic!("expected a token");
+    };
+    assert_eq!(token.text(), ".");
+
+    let Some(Descendant::TokenReference(token)) = at_byte(27) else {
+        panic!("expected a token");
+    };
+    assert_eq!(token.text(), "bar");
+
+    // Inside of whitespace between tokens
+    let Some(Descendant::Node(node)) = at_byte(19) else {
+        panic!("expected a node");
+    };
+    assert_eq!(node.text(), "local x   = foo.bar");
+
+    // Inside of a leading comment
+    assert!(at_byte(3).is_none());
+}
+
+#[test]
+fn test_span_merge() {
+    let ast = parse("local x = 1\ndo end\nprint(x)").unwrap();
+    let stmts = ast.nodes().stmts().collect::<Vec<_>>();
+    let bytes = |(start, end): (Position, Position)| (start.bytes(), end.bytes());
+
+    assert_eq!(span::merge(stmts[0], stmts[2]).map(bytes), Some((0, 27)));
+    assert_eq!(span::merge(stmts[2], stmts[0]).map(bytes), Some((0, 27)));
+    assert_eq!(span::merge(stmts[1], stmts[1]).map(bytes), Some((12, 18)));
+
+    let ast::Stmt::Do(do_stmt) = stmts[1] else {
+        panic!("expected a do statement");
+    };
+
+    // Empty blocks have no position, so only the other node is used
+    assert_eq!(do_stmt.block().start_position(), None);
+    assert_eq!(
+        span::merge(do_stmt.block(), stmts[2]).map(bytes),
+        Some((19, 27))
+    );
+    assert_eq!(span::merge(do_stmt.block(), do_stmt.block()), None);
+}
+
+#[cfg(feature = "lsp")]
+#[test]
+fn test_utf16_positions() {
+    let source = "local a = '😀' local b = 2\nprint('é😀', b)";
+    let ast = parse(source).unwrap();
+
+    let b_positions: Vec<_> = ast
+        .tokens()
+        .filter(|token| token.token().to_string() == "b")
+        .map(|token| token.token().start_position())
+        .collect();
+
+    assert_eq!(
+        b_positions
+            .iter()
+            .map(|position| (position.bytes(), position.line(), position.character()))
+            .collect::<Vec<_>>(),
+        vec![(23, 1, 21), (45, 2, 13)]
+    );
+
+    // The emoji take up two UTF-16 code units, but only one character
+    assert_eq!(b_positions[0].to_utf16(source), (0, 21));
+    assert_eq!(b_positions[1].to_utf16(source), (1, 13));
+
+    for token in ast.tokens() {
+        let position = token.token().start_position();
+        let (line, column) = position.to_utf16(source);
+        assert_eq!(Position::from_utf16(source, line, column), Some(position));
+    }
+
+    // In the middle of the emoji
+    assert_eq!(
+        Position::from_utf16(source, 0, 12),
+        Some(Position {
+            bytes: 11,
+            line: 1,
+            character: 12
+        })
+    );
+
+    // Past the end of the line
+    assert_eq!(
+        Position::from_utf16(source, 0, 1000),
+        Some(Position {
+            bytes: 28,
+            line: 1,
+            character: 26
+        })
+    );
+
+    assert_eq!(Position::from_utf16(source, 2, 0), None);
+}
@@ -0,0 +1,67 @@
+use full_moon::{node::Node, parse, print_minified};
+use std::fs;
+
+mod common;
+use common::run_test_folder;
+
+fn assert_minifies(code: &str, expected: &str) {
+    let ast = parse(code).unwrap();
+    let minified = print_minified(&ast);
+    assert_eq!(minified, expected);
+
+    let reparsed = parse(&minified).unwrap();
+    assert!(
+        ast.nodes().similar(reparsed.nodes()),
+        "minified code parsed differently: {minified}"
+    );
+}
+
+#[test]
+fn test_minified() {
+    assert_minifies("local x = 1", "local x=1");
+    assert_minifies(
+        "-- comment\nlocal function f(a, b)\n    return a + b -- sum\nend\n",
+        "local function f(a,b)return a+b end",
+    );
+    assert_minifies(
+        "if a then b() elseif c then d() else e() end",
+        "if a then b()elseif c then d()else e()end",
+    );
+    assert_minifies("call { 1, 2 } call 'x'", "call{1,2}call'x'");
+}
+
+#[test]
+fn test_minified_edge_cases() {
+    assert_minifies("x = a - -b", "x=a- -b");
+    assert_minifies("x = - -1", "x=- -1");
+    assert_minifies("x = 1 .. 2", "x=1 ..2");
+    assert_minifies("x = a .. .5", "x=a.. .5");
+    assert_minifies("x = t[ [[key]] ]", "x=t[ [[key]]]");
+    assert_minifies("x = t[ [=[key]=] ]", "x=t[ [=[key]=]]");
+    assert_minifies("x = a == b", "x=a==b");
+    assert_minifies("return 1", "return 1");
+}
+
+#[test]
+#[cfg(feature = "lua54")]
+fn test_minified_attributes() {
+    assert_minifies("local x <const> = 1", "local x<const> =1");
+}
+
+#[test]
+#[cfg_attr(feature = "no-source-tests", ignore)]
+fn test_minified_cases() {
+    run_test_folder("./tests/cases/pass", |path| {
+        let source = fs::read_to_string(path.join("source.lua")).expect("couldn't read source.lua");
+        let ast = parse(&source).unwrap();
+        let minified = print_minified(&ast);
+
+        let reparsed = parse(&minified)
+            .unwrap_or_else(|error| panic!("couldn't reparse {path:?} - {error:#?}"));
+
+        assert!(
+            ast.nodes().similar(reparsed.nodes()),
+            "{path:?} parsed differently when minified: {minified}"
+        );
+    });
+}
@@ -0,0 +1,75 @@
+use full_moon::{parse, print_normalized, PrintOptions};
+
+fn normalize(code: &str, options: PrintOptions) -> String {
+    print_normalized(&parse(code).unwrap(), options)
+}
+
+#[test]
+fn test_spacing() {
+    assert_eq!(
+        normalize("local x=1+2*-y", PrintOptions::new()),
+        "local x = 1 + 2 * -y\n"
+    );
+
+    assert_eq!(
+        normalize("call ( a,b ) . c : d ( not e , # f )", PrintOptions::new()),
+        "call(a, b).c:d(not e, #f)\n"
+    );
+
+    assert_eq!(
+        normalize("local t = { 1 , [ 'x' ] = 2 ; }", PrintOptions::new()),
+        "local t = {1, ['x'] = 2;}\n"
+    );
+
+    assert_eq!(
+        normalize("x = 1 - - 1", PrintOptions::new()),
+        "x = 1 - -1\n"
+    );
+    assert_eq!(normalize("x = - - 1", PrintOptions::new()), "x = - -1\n");
+}
+
+#[test]
+fn test_statements_and_indentation() {
+    let code = "local function f(a) if a then return 1 elseif b then return 2 else while true do break end end end f(1) f(2)";
+
+    assert_eq!(
+        normalize(code, PrintOptions::new()),
+        "local function f(a)\n    if a then\n        return 1\n    elseif b then\n        return 2\n    else\n        while true do\n            break\n        end\n    end\nend\nf(1)\nf(2)\n"
+    );
+
+    assert_eq!(
+        normalize(code, PrintOptions::new().with_use_tabs(true)),
+        "local function f(a)\n\tif a then\n\t\treturn 1\n\telseif b then\n\t\treturn 2\n\telse\n\t\twhile true do\n\t\t\tbreak\n\t\tend\n\tend\nend\nf(1)\nf(2)\n"
+    );
+
+    assert_eq!(
+        normalize(
+            "repeat x() until y local f = function() end",
+            PrintOptions::new().with_indent_width(2)
+        ),
+        "repeat\n  x()\nuntil y\nlocal f = function() end\n"
+    );
+}
+
+#[test]
+fn test_comments() {
+    assert_eq!(
+        normalize(
+            "-- header\nlocal x = 1 -- trailing\ndo\n--[[ block ]]\nprint(x) end",
+            PrintOptions::new()
+        ),
+        "-- header\nlocal x = 1 -- trailing\ndo\n    --[[ block ]]\n    print(x)\nend\n"
+    );
+}
+
+#[test]
+fn test_normalized_is_stable() {
+    let code = "local a,b=1,{2,3} for i=1,#b do if b[i]then a=a+b[i]end end return a";
+    let normalized = normalize(code, PrintOptions::new());
+    assert_eq!(normalize(&normalized, PrintOptions::new()), normalized);
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(normalize("", PrintOptions::new()), "");
+}
@@ -0,0 +1,295 @@
+use full_moon::{
+    ast::{
+        self,
+        punctuated::{Pair, Punctuated},
+    },
+    parse, print,
+    tokenizer::TokenReference,
+};
+
+fn identifier(name: &str) -> ast::Expression {
+    ast::Expression::Var(ast::Var::Name(TokenReference::identifier(name)))
+}
+
+// Parses a single function call statement, runs `callback` over its arguments, and prints the result
+fn with_call_arguments(
+    code: &str,
+    callback: impl FnOnce(&mut Punctuated<ast::Expression>),
+) -> String {
+    let ast = parse(code).unwrap();
+    let mut stmts: Vec<_> = ast.nodes().stmts_with_semicolon().cloned().collect();
+
+    let (ast::Stmt::FunctionCall(call), semicolon) = stmts.remove(0) else {
+        panic!("expected a function call");
+    };
+
+    let mut suffixes: Vec<_> = call.suffixes().cloned().collect();
+
+    let ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+        parentheses,
+        mut arguments,
+    })) = suffixes.remove(0)
+    else {
+        panic!("expected parentheses arguments");
+    };
+
+    callback(&mut arguments);
+
+    suffixes.insert(
+        0,
+        ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+            parentheses,
+            arguments,
+        })),
+    );
+
+    stmts.insert(
+        0,
+        (
+            ast::Stmt::FunctionCall(call.with_suffixes(suffixes)),
+            semicolon,
+        ),
+    );
+
+    let nodes = ast.nodes().clone().with_stmts(stmts);
+    print(&ast.with_nodes(nodes))
+}
+
+#[test]
+fn test_remove() {
+    assert_eq!(
+        with_call_arguments("call(a, b, c)", |arguments| {
+            arguments.remove(0);
+        }),
+        "call(b, c)"
+    );
+
+    assert_eq!(
+        with_call_arguments("call(a, b, c)", |arguments| {
+            arguments.remove(1);
+        }),
+        "call(a, c)"
+    );
+
+    assert_eq!(
+        with_call_arguments("call(a, b, c)", |arguments| {
+            arguments.remove(2);
+        }),
+        "call(a, b)"
+    );
+}
+
+#[test]
+fn test_insert() {
+    assert_eq!(
+        with_call_arguments("call()", |arguments| {
+            arguments.insert(0, identifier("a"), TokenReference::symbol(", ").unwrap());
+        }),
+        "call(a)"
+    );
+
+    assert_eq!(
+        with_call_arguments("call(a, b)", |arguments| {
+            arguments.insert(2, identifier("c"), TokenReference::symbol(", ").unwrap());
+        }),
+        "call(a, b, c)"
+    );
+
+    assert_eq!(
+        with_call_arguments("call(b, c)", |arguments| {
+            arguments.insert(0, identifier("a"), TokenReference::symbol(", ").unwrap());
+        }),
+        "call(a, b, c)"
+    );
+}
+
+#[test]
+fn test_index() {
+    let ast = parse("call(a, b)").unwrap();
+
+    let Some(ast::Stmt::FunctionCall(call)) = ast.nodes().stmts().next() else {
+        panic!("expected a function call");
+    };
+
+    let Some(ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+        arguments,
+        ..
+    }))) = call.suffixes().next()
+    else {
+        panic!("expected parentheses arguments");
+    };
+
+    assert_eq!(arguments[0].to_string(), "a");
+    assert_eq!(arguments[1].to_string(), "b");
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_index_out_of_bounds() {
+    let punctuated: Punctuated<ast::Expression> = Punctuated::new();
+    let _ = &punctuated[0];
+}
+
+// Parses `local t = { ... }`, runs `callback` over the table's fields, and prints the result
+fn with_table_fields(code: &str, callback: impl FnOnce(&mut Punctuated<ast::Field>)) -> String {
+    let ast = parse(code).unwrap();
+    let mut stmts: Vec<_> = ast.nodes().stmts_with_semicolon().cloned().collect();
+
+    let (ast::Stmt::LocalAssignment(local_assignment), semicolon) = stmts.remove(0) else {
+        panic!("expected a local assignment");
+    };
+
+    let mut expressions = local_assignment.expressions().clone();
+
+    let Some(ast::Expression::TableConstructor(table)) =
+        expressions.pop().map(|pair| pair.into_value())
+    else {
+        panic!("expected a table constructor");
+    };
+
+    let mut fields = table.fields().clone();
+    callback(&mut fields);
+
+    expressions.push(Pair::End(ast::Expression::TableConstructor(
+        table.with_fields(fields),
+    )));
+
+    stmts.insert(
+        0,
+        (
+            ast::Stmt::LocalAssignment(local_assignment.with_expressions(expressions)),
+            semicolon,
+        ),
+    );
+
+    let nodes = ast.nodes().clone().with_stmts(stmts);
+    print(&ast.with_nodes(nodes))
+}
+
+#[test]
+fn test_retain() {
+    let code = with_table_fields("local t = { 1, 2, 3, 4 }", |fields| {
+        fields.retain(|field| match field {
+            ast::Field::NoKey(value) => value.to_string().trim().parse::<u32>().unwrap() % 2 != 0,
+            _ => unreachable!(),
+        });
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields.to_string(), "1, 3");
+    });
+
+    // The space before `}` was trailing trivia of the removed `4`
+    assert_eq!(code, "local t = { 1, 3}");
+    assert!(parse(&code).is_ok());
+
+    let code = with_table_fields("local t = { 1, 2, 3, 4 }", |fields| {
+        fields.retain(|_| false);
+        assert!(fields.is_empty());
+    });
+
+    assert_eq!(code, "local t = {  }");
+    assert!(parse(&code).is_ok());
+}
+
+#[test]
+fn test_from_values() {
+    let names = Punctuated::from_values(
+        ["a", "b", "c"].map(TokenReference::identifier),
+        TokenReference::symbol(", ").unwrap(),
+    );
+
+    assert_eq!(names.len(), 3);
+    assert!(names.last().unwrap().punctuation().is_none());
+    assert_eq!(names.to_string(), "a, b, c");
+}
+
+#[test]
+fn test_extend_values() {
+    let comma = TokenReference::symbol(", ").unwrap();
+
+    let mut names = Punctuated::new();
+    names.extend_values(["a", "b"].map(TokenReference::identifier), comma.clone());
+    names.extend_values(["c", "d"].map(TokenReference::identifier), comma.clone());
+    names.extend_values(Vec::new(), comma.clone());
+
+    assert_eq!(names.len(), 4);
+    assert!(names.last().unwrap().punctuation().is_none());
+    assert_eq!(names.to_string(), "a, b, c, d");
+
+    assert_eq!(
+        with_call_arguments("call(a, b)", |arguments| {
+            arguments.extend_values(vec![identifier("c")], comma.clone());
+        }),
+        "call(a, b, c)"
+    );
+}
+
+#[test]
+fn test_map() {
+    let doubled = with_call_arguments("call(1, 2,  3)", |arguments| {
+        *arguments = std::mem::take(arguments).map(|argument| {
+            let value = argument.as_number().unwrap();
+            ast::Expression::number(&(value * 2.0).to_string())
+        });
+    });
+
+    assert_eq!(doubled, "call(2, 4,  6)");
+
+    let numbers = Punctuated::from_values(vec![1, 2, 3], TokenReference::symbol(", ").unwrap());
+    let strings = numbers.map(|number| number.to_string());
+    assert_eq!(strings.len(), 3);
+    assert_eq!(strings.punctuation().count(), 2);
+    assert_eq!(
+        strings.into_values().collect::<Vec<_>>(),
+        vec!["1", "2", "3"]
+    );
+}
+
+#[test]
+fn test_clear_and_truncate() {
+    let mut names = Punctuated::from_values(
+        ["a", "b", "c", "d"].map(TokenReference::identifier),
+        TokenReference::symbol(", ").unwrap(),
+    );
+
+    names.truncate(10);
+    assert_eq!(names.to_string(), "a, b, c, d");
+
+    names.truncate(2);
+    assert_eq!(names.len(), 2);
+    assert!(names.last().unwrap().punctuation().is_none());
+    assert_eq!(names.to_string(), "a, b");
+
+    names.clear();
+    assert!(names.is_empty());
+    assert_eq!(names.to_string(), "");
+
+    assert_eq!(
+        with_call_arguments("call(a, b, c)", |arguments| arguments.truncate(0)),
+        "call()"
+    );
+}
+
+#[test]
+fn test_punctuation() {
+    let ast = parse("call(a ,b,  c)").unwrap();
+
+    let Some(ast::Stmt::FunctionCall(call)) = ast.nodes().stmts().next() else {
+        panic!("expected a function call");
+    };
+
+    let Some(ast::Suffix::Call(ast::Call::AnonymousCall(ast::FunctionArgs::Parentheses {
+        arguments,
+        ..
+    }))) = call.suffixes().next()
+    else {
+        panic!("expected parentheses arguments");
+    };
+
+    let punctuation: Vec<_> = arguments
+        .punctuation()
+        .map(|token| token.to_string())
+        .collect();
+
+    assert_eq!(punctuation, vec![",", ",  "]);
+}
@@ -0,0 +1,150 @@
+use full_moon::{parse, resolver::Resolver};
+
+// For every occurrence of `name` in the code, the index of the occurrence that declares it
+fn declarations(code: &str, name: &str) -> Vec<Option<usize>> {
+    let ast = parse(code).unwrap();
+    let resolver = Resolver::new(&ast);
+    let names: Vec<_> = ast.tokens().filter(|token| token.text() == name).collect();
+
+    names
+        .iter()
+        .map(|token| {
+            let declaration = resolver.resolve(token)?;
+            names.iter().position(|name| *name == declaration)
+        })
+        .collect()
+}
+
+#[test]
+fn test_shadowing() {
+    let code = r#"
+        local x = 1
+        local function f(x)
+            if x then
+                local x = x
+                print(x)
+            end
+
+            return x
+        end
+        print(x)
+        "#;
+
+    assert_eq!(
+        declarations(code, "x"),
+        vec![
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(3),
+            Some(1),
+            Some(3),
+            Some(1),
+            Some(0),
+        ]
+    );
+
+    assert_eq!(
+        declarations("local x = 1 local x = x + 1 print(x)", "x"),
+        vec![Some(0), Some(1), Some(0), Some(1)]
+    );
+}
+
+#[test]
+fn test_block_boundaries() {
+    assert_eq!(
+        declarations("do local x = 1 end print(x)", "x"),
+        vec![Some(0), None]
+    );
+
+    assert_eq!(
+        declarations("while true do local x end x = 1", "x"),
+        vec![Some(0), None]
+    );
+
+    assert_eq!(
+        declarations(
+            "if a then local x elseif b then x() else local x x() end",
+            "x"
+        ),
+        vec![Some(0), None, Some(2), Some(2)]
+    );
+
+    // The condition of `until` can see the locals of the loop
+    assert_eq!(
+        declarations("repeat local x = f() until x print(x)", "x"),
+        vec![Some(0), Some(0), None]
+    );
+}
+
+#[test]
+fn test_loops() {
+    assert_eq!(
+        declarations("local i = 5 for i = i, 10 do print(i) end print(i)", "i"),
+        vec![Some(0), Some(1), Some(0), Some(1), Some(0)]
+    );
+
+    assert_eq!(
+        declarations("for k, v in pairs(v) do print(k, v) end", "v"),
+        vec![Some(0), None, Some(0)]
+    );
+}
+
+#[test]
+fn test_functions() {
+    // Local functions can refer to themselves, unlike functions assigned to a local
+    assert_eq!(
+        declarations("local function f() f() end", "f"),
+        vec![Some(0), Some(0)]
+    );
+    assert_eq!(
+        declarations("local f = function() f() end f()", "f"),
+        vec![Some(0), None, Some(0)]
+    );
+
+    assert_eq!(
+        declarations("local t = {} function t.a(t) return t end t.b = 1", "t"),
+        vec![Some(0), Some(0), Some(1), Some(1), Some(0)]
+    );
+
+    // The implicit `self` of a method resolves to the name of the method
+    let code = "function t:method() return self end";
+    let ast = parse(code).unwrap();
+    let resolver = Resolver::new(&ast);
+    let self_token = ast.tokens().find(|token| token.text() == "self").unwrap();
+    assert_eq!(resolver.resolve(self_token).unwrap().text(), "method");
+    assert_eq!(declarations(code, "t"), vec![None]);
+
+    // Names that aren't variables don't resolve
+    assert_eq!(
+        declarations("local y = 1 print(x.y, x:y(), { y = 1 })", "y"),
+        vec![Some(0), None, None, None]
+    );
+}
+
+#[test]
+fn test_free_variables() {
+    let free_variables = |code| {
+        parse(code)
+            .unwrap()
+            .free_variables()
+            .into_iter()
+            .map(|token| token.text().to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        free_variables("local count = 0 for i = 1, limit do count = cuont + i end"),
+        vec!["limit", "cuont"]
+    );
+
+    assert_eq!(
+        free_variables("x = 1 local y = x function f(a) return a, b end"),
+        vec!["x", "x", "f", "b"]
+    );
+
+    assert_eq!(
+        free_variables("local t = {} function t:method() return self.value end"),
+        Vec::<String>::new()
+    );
+}
@@ -0,0 +1,114 @@
+#![cfg(any(feature = "json", feature = "binary"))]
+use full_moon::{
+    ast::{Ast, SerializationError, SCHEMA_VERSION},
+    parse, print,
+};
+
+#[cfg(feature = "binary")]
+use std::fs;
+
+#[cfg(feature = "binary")]
+mod common;
+#[cfg(feature = "binary")]
+use common::run_test_folder;
+
+const SOURCE: &str =
+    "-- comment\nlocal function f(a, b)\n    return a + b\nend\n\nprint(f(1, 2))\n";
+
+#[test]
+#[cfg(feature = "json")]
+fn test_json_round_trip() {
+    let ast = parse(SOURCE).unwrap();
+    let json = ast.to_json();
+
+    let deserialized = Ast::from_json(&json).unwrap();
+    assert!(ast.similar(&deserialized));
+    assert_eq!(print(&deserialized), SOURCE);
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_json_schema_version() {
+    let json = parse(SOURCE).unwrap().to_json();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["schema_version"], SCHEMA_VERSION);
+
+    let outdated = json.replacen(
+        &format!("\"schema_version\":{SCHEMA_VERSION}"),
+        &format!("\"schema_version\":{}", SCHEMA_VERSION + 1),
+        1,
+    );
+
+    match Ast::from_json(&outdated) {
+        Err(SerializationError::SchemaVersion { expected, found }) => {
+            assert_eq!(expected, SCHEMA_VERSION);
+            assert_eq!(found, SCHEMA_VERSION + 1);
+        }
+
+        other => panic!("expected a schema version error, got {other:?}"),
+    }
+
+    assert!(matches!(
+        Ast::from_json("{}"),
+        Err(SerializationError::Json(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "binary")]
+fn test_bytes_round_trip() {
+    let ast = parse(SOURCE).unwrap();
+    let bytes = ast.to_bytes();
+
+    let deserialized = Ast::from_bytes(&bytes).unwrap();
+    assert!(ast.similar(&deserialized));
+    assert_eq!(print(&deserialized), SOURCE);
+}
+
+#[test]
+#[cfg(all(feature = "binary", feature = "json"))]
+fn test_bytes_smaller_than_json() {
+    let ast = parse(SOURCE).unwrap();
+    assert!(ast.to_bytes().len() < ast.to_json().len());
+}
+
+#[test]
+#[cfg(feature = "binary")]
+fn test_bytes_schema_version() {
+    assert!(matches!(
+        Ast::from_bytes(&[]),
+        Err(SerializationError::Binary(_))
+    ));
+
+    let mut bytes = parse(SOURCE).unwrap().to_bytes();
+
+    // The schema version is the first value in the map, written as a positive fixint
+    let position = bytes
+        .windows(b"schema_version".len())
+        .position(|window| window == b"schema_version")
+        .unwrap()
+        + b"schema_version".len();
+    assert_eq!(u32::from(bytes[position]), SCHEMA_VERSION);
+    bytes[position] += 1;
+
+    assert!(matches!(
+        Ast::from_bytes(&bytes),
+        Err(SerializationError::SchemaVersion { found, .. }) if found == SCHEMA_VERSION + 1
+    ));
+}
+
+#[test]
+#[cfg(feature = "binary")]
+#[cfg_attr(feature = "no-source-tests", ignore)]
+fn test_bytes_pass_cases() {
+    run_test_folder("./tests/cases/pass", |path| {
+        let source = fs::read_to_string(path.join("source.lua")).expect("couldn't read source.lua");
+        let ast = parse(&source).unwrap();
+
+        let deserialized = Ast::from_bytes(&ast.to_bytes())
+            .unwrap_or_else(|error| panic!("couldn't deserialize {path:?} - {error}"));
+
+        assert!(ast.similar(&deserialized));
+        assert_eq!(print(&deserialized), source);
+    });
+}
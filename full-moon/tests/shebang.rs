@@ -0,0 +1,44 @@
+use full_moon::{parse, print, tokenizer::TokenType};
+
+#[test]
+fn test_shebang() {
+    let code = "#!/usr/bin/env lua\nprint(1)";
+    let ast = parse(code).unwrap();
+
+    let shebang = ast.shebang().unwrap();
+    assert_eq!(
+        shebang.token_type(),
+        &TokenType::Shebang {
+            line: "#!/usr/bin/env lua".into()
+        }
+    );
+    assert_eq!(print(&ast), code);
+    assert_eq!(ast.nodes().stmts().count(), 1);
+
+    let ast = ast.without_shebang();
+    assert!(ast.shebang().is_none());
+    assert_eq!(print(&ast), "print(1)");
+
+    let ast = ast.update_positions();
+    let first_token = ast.tokens().next().unwrap();
+    assert_eq!(first_token.token().start_position().bytes(), 0);
+    assert_eq!(first_token.token().start_position().line(), 1);
+}
+
+#[test]
+fn test_no_shebang() {
+    let ast = parse("-- #!/usr/bin/env lua\nprint(1)").unwrap();
+    assert!(ast.shebang().is_none());
+    assert_eq!(
+        print(&ast.without_shebang()),
+        "-- #!/usr/bin/env lua\nprint(1)"
+    );
+}
+
+#[test]
+fn test_shebang_only() {
+    let ast = parse("#!/usr/bin/env lua\n").unwrap();
+    assert!(ast.shebang().is_some());
+    assert_eq!(print(&ast), "#!/usr/bin/env lua\n");
+    assert_eq!(print(&ast.without_shebang()), "");
+}
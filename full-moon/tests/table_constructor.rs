@@ -0,0 +1,80 @@
+use full_moon::{ast, parse};
+
+fn table(code: &str) -> ast::TableConstructor {
+    let ast = parse(&format!("local t = {code}")).unwrap();
+    let Some(ast::Stmt::LocalAssignment(local_assignment)) = ast.nodes().stmts().next() else {
+        panic!("expected a local assignment");
+    };
+
+    match local_assignment.expressions().iter().next() {
+        Some(ast::Expression::TableConstructor(table)) => table.clone(),
+        _ => panic!("expected a table constructor"),
+    }
+}
+
+#[test]
+fn test_get_field() {
+    let table = table("{ name = 'moon', ['size'] = 3, [\"x\"] = true, 'name', [1] = 2 }");
+
+    assert_eq!(
+        table
+            .get_field("name")
+            .and_then(|value| value.as_string())
+            .as_deref(),
+        Some("moon")
+    );
+    assert_eq!(
+        table.get_field("size").and_then(ast::Expression::as_number),
+        Some(3.0)
+    );
+    assert_eq!(
+        table.get_field("x").and_then(ast::Expression::as_bool),
+        Some(true)
+    );
+    assert!(table.get_field("1").is_none());
+    assert!(table.get_field("missing").is_none());
+}
+
+#[test]
+fn test_get_field_duplicate() {
+    let table = table("{ x = 1, x = 2 }");
+    assert_eq!(
+        table.get_field("x").and_then(ast::Expression::as_number),
+        Some(2.0)
+    );
+}
+
+#[test]
+fn test_array_values() {
+    assert_eq!(table("{ x = 1 }").array_values().count(), 0);
+
+    let table = table("{ 1, 2, x = 4, [4] = 5, 3 }");
+    let values = table
+        .array_values()
+        .filter_map(ast::Expression::as_number)
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_duplicate_keys() {
+    assert!(table("{ a = 1, b = 2, ['c'] = 3, 'a' }")
+        .duplicate_keys()
+        .is_empty());
+
+    let table = table(r#"{ a = 1, ["a"] = 2, b = 3, [('b')] = 4, [c] = 5, [c] = 6, ["\97"] = 7 }"#);
+    let duplicates = table
+        .duplicate_keys()
+        .into_iter()
+        .map(|(first, duplicate)| (first.token().to_string(), duplicate.token().to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        duplicates,
+        vec![
+            ("a".to_owned(), "\"a\"".to_owned()),
+            ("b".to_owned(), "'b'".to_owned()),
+            ("a".to_owned(), r#""\97""#.to_owned()),
+        ]
+    );
+}
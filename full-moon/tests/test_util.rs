@@ -0,0 +1,18 @@
+#![cfg(feature = "test-util")]
+use full_moon::test_util::assert_round_trips;
+
+#[test]
+fn test_assert_round_trips() {
+    assert_round_trips("");
+    assert_round_trips("local x = 1");
+    assert_round_trips(
+        "-- comment\nlocal function f(a, ...)\n\treturn a --[[ inline ]] + 1\nend\n",
+    );
+    assert_round_trips("local t = { 1, 2; x = \"y\", [ [[z]] ] = 3 }\r\nprint(#t)");
+}
+
+#[test]
+fn test_assert_round_trips_skips_errors() {
+    assert_round_trips("local x = ");
+    assert_round_trips("if x then");
+}
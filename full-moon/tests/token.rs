@@ -0,0 +1,384 @@
+use full_moon::{
+    ast::{self, LuaVersion},
+    tokenizer::{
+        tokens, Lexer, LexerResult, StringLiteralQuoteType, Symbol, Token, TokenKind,
+        TokenReference, TokenType, TokenizerErrorType,
+    },
+};
+
+fn number(text: &str) -> Token {
+    Token::new(TokenType::Number { text: text.into() })
+}
+
+#[test]
+fn test_parse_number() {
+    assert_eq!(number("0xFF").parse_number(), Some(255.0));
+    assert_eq!(number("1e3").parse_number(), Some(1000.0));
+    assert_eq!(number("0b1010").parse_number(), Some(10.0));
+    assert_eq!(number(".5").parse_number(), Some(0.5));
+    assert_eq!(number("1_000").parse_number(), Some(1000.0));
+    assert_eq!(number("0x1p4").parse_number(), Some(16.0));
+    assert_eq!(number("0xA.8").parse_number(), Some(10.5));
+
+    assert_eq!(
+        Token::new(TokenType::Identifier {
+            identifier: "x".into()
+        })
+        .parse_number(),
+        None
+    );
+}
+
+#[test]
+fn test_parse_integer() {
+    assert_eq!(number("0xFF").parse_integer(), Some(255));
+    assert_eq!(number("0b1010").parse_integer(), Some(10));
+    assert_eq!(number("42").parse_integer(), Some(42));
+    assert_eq!(number("0xFFFFFFFFFFFFFFFF").parse_integer(), Some(-1));
+
+    assert_eq!(number("1e3").parse_integer(), None);
+    assert_eq!(number(".5").parse_integer(), None);
+    assert_eq!(number("99999999999999999999").parse_integer(), None);
+}
+
+fn string(literal: &str, quote_type: StringLiteralQuoteType) -> Token {
+    Token::new(TokenType::StringLiteral {
+        literal: literal.into(),
+        multi_line_depth: 0,
+        quote_type,
+    })
+}
+
+#[test]
+fn test_string_value() {
+    let double = |literal| string(literal, StringLiteralQuoteType::Double);
+
+    assert_eq!(double("hello").string_value().unwrap(), "hello");
+    assert_eq!(double(r"\65\066\0670").string_value().unwrap(), "ABC0");
+    assert_eq!(double(r"\x41\x62").string_value().unwrap(), "Ab");
+    assert_eq!(double("a\\z  \n\t  b").string_value().unwrap(), "ab");
+    assert_eq!(double("a\\\nb").string_value().unwrap(), "a\nb");
+    assert_eq!(double(r#"\"\'\\\t"#).string_value().unwrap(), "\"'\\\t");
+    assert_eq!(double(r"\u{48}\u{49}").string_value().unwrap(), "HI");
+
+    assert_eq!(double(r"\x4").string_value(), None);
+    assert_eq!(double(r"\256").string_value(), None);
+    assert_eq!(double(r"\q").string_value(), None);
+
+    // Bracket strings have no escape sequences
+    assert_eq!(
+        string(r"\65", StringLiteralQuoteType::Brackets)
+            .string_value()
+            .unwrap(),
+        r"\65"
+    );
+
+    assert_eq!(number("1").string_value(), None);
+}
+
+#[test]
+fn test_quote_type_open_close() {
+    let open_close = |quote_type: StringLiteralQuoteType, depth| {
+        let (open, close) = quote_type.open_close(depth);
+        format!("{open}x{close}")
+    };
+
+    assert_eq!(open_close(StringLiteralQuoteType::Brackets, 0), "[[x]]");
+    assert_eq!(open_close(StringLiteralQuoteType::Brackets, 2), "[==[x]==]");
+    assert_eq!(open_close(StringLiteralQuoteType::Double, 0), "\"x\"");
+    assert_eq!(open_close(StringLiteralQuoteType::Single, 3), "'x'");
+
+    let bracket_string = Token::new(TokenType::StringLiteral {
+        literal: "x".into(),
+        multi_line_depth: 1,
+        quote_type: StringLiteralQuoteType::Brackets,
+    });
+    assert_eq!(bracket_string.to_string(), "[=[x]=]");
+    assert_eq!(
+        string("x", StringLiteralQuoteType::Single).to_string(),
+        "'x'"
+    );
+}
+
+#[test]
+fn test_with_token_type() {
+    let ast = full_moon::parse("local  x  = 1").unwrap();
+    let Some(ast::Stmt::LocalAssignment(local_assignment)) = ast.nodes().stmts().next() else {
+        panic!("expected a local assignment");
+    };
+
+    let name = local_assignment.names().iter().next().unwrap();
+    let renamed = name.with_token_type(TokenType::Identifier {
+        identifier: "renamed".into(),
+    });
+
+    assert_eq!(renamed.to_string(), "renamed  ");
+    assert_eq!(
+        renamed.token().start_position(),
+        name.token().start_position()
+    );
+    assert_eq!(renamed.token().end_position(), name.token().end_position());
+    assert!(renamed.leading_trivia().eq(name.leading_trivia()));
+}
+
+#[test]
+fn test_trivia_helpers() {
+    let name = TokenReference::identifier("x");
+
+    let spaced = name
+        .with_leading_trivia(vec![Token::new(TokenType::spaces(2))])
+        .with_trailing_trivia(vec![Token::new(TokenType::tabs(1))]);
+    assert_eq!(spaced.to_string(), "  x\t");
+
+    let spaced = spaced.with_leading_whitespace("\n").unwrap();
+    assert_eq!(spaced.to_string(), "\nx\t");
+
+    let spaced = spaced.with_trailing_whitespace("").unwrap();
+    assert_eq!(spaced.to_string(), "\nx");
+    assert_eq!(spaced.trailing_trivia().count(), 0);
+
+    assert_eq!(
+        name.with_trailing_whitespace(" ; "),
+        Err(TokenizerErrorType::UnexpectedToken(';'))
+    );
+}
+
+#[test]
+fn test_whitespace_constructors() {
+    assert_eq!(
+        TokenType::newlines(3),
+        TokenType::Whitespace {
+            characters: "\n\n\n".into()
+        }
+    );
+    assert_eq!(TokenType::newlines(0).to_string(), "");
+
+    assert_eq!(
+        TokenType::whitespace(" \t\r\n"),
+        Ok(TokenType::Whitespace {
+            characters: " \t\r\n".into()
+        })
+    );
+    assert_eq!(TokenType::whitespace(""), Ok(TokenType::spaces(0)));
+    assert_eq!(
+        TokenType::whitespace("\n x"),
+        Err(TokenizerErrorType::UnexpectedToken('x'))
+    );
+    assert_eq!(
+        TokenType::whitespace("\u{a0}"),
+        Err(TokenizerErrorType::UnexpectedToken('\u{a0}'))
+    );
+}
+
+#[test]
+fn test_comment_predicates() {
+    let single = Token::new(TokenType::SingleLineComment {
+        comment: " hello".into(),
+    });
+    let multi = Token::new(TokenType::MultiLineComment {
+        blocks: 0,
+        comment: " world ".into(),
+    });
+    let shebang = Token::new(TokenType::Shebang {
+        line: "#!/usr/bin/env lua".into(),
+    });
+    let whitespace = Token::new(TokenType::spaces(1));
+
+    assert!(single.is_comment());
+    assert!(multi.is_comment());
+    assert!(!shebang.is_comment());
+    assert!(!whitespace.is_comment());
+
+    let name = TokenReference::identifier("x")
+        .with_leading_trivia(vec![whitespace.clone()])
+        .with_trailing_trivia(vec![whitespace, single]);
+    assert!(!name.has_leading_comments());
+    assert!(name.has_trailing_comments());
+
+    let name = name.with_leading_trivia(vec![multi]);
+    assert!(name.has_leading_comments());
+}
+
+#[test]
+fn test_symbol_from_str() {
+    assert_eq!(Symbol::from_str("local"), Some(Symbol::Local));
+    assert_eq!(Symbol::from_str("..."), Some(Symbol::Ellipse));
+    assert_eq!(Symbol::from_str("~="), Some(Symbol::TildeEqual));
+    assert_eq!(Symbol::from_str("Local"), None);
+    assert_eq!(Symbol::from_str(" local"), None);
+    assert_eq!(Symbol::from_str(""), None);
+
+    #[cfg(feature = "lua52")]
+    assert_eq!(Symbol::from_str("goto"), Some(Symbol::Goto));
+    #[cfg(not(feature = "lua52"))]
+    assert_eq!(Symbol::from_str("goto"), None);
+
+    #[cfg(feature = "luau")]
+    assert_eq!(Symbol::from_str("+="), Some(Symbol::PlusEqual));
+    #[cfg(not(feature = "luau"))]
+    assert_eq!(Symbol::from_str("+="), None);
+}
+
+#[test]
+fn test_lexer_tokens_iter() {
+    let tokens = Lexer::new("local x = 1 -- one", LuaVersion::new())
+        .tokens_iter()
+        .map(|token| token.unwrap().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec!["local ", "x ", "= ", "1 -- one", ""]);
+
+    let identifiers = Lexer::new("a + b * c", LuaVersion::new())
+        .tokens_iter()
+        .filter_map(|token| match token.unwrap().token_type() {
+            TokenType::Identifier { identifier } => Some(identifier.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(identifiers, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_lexer_peek_nth() {
+    fn text(token: Option<&LexerResult<TokenReference>>) -> Option<String> {
+        match token? {
+            LexerResult::Ok(token) => Some(token.token().to_string()),
+            _ => panic!("expected a token"),
+        }
+    }
+
+    let mut lexer = Lexer::new("local x = 1", LuaVersion::new());
+
+    assert_eq!(text(lexer.peek_nth(3)), Some("1".to_owned()));
+    assert_eq!(text(lexer.peek_nth(2)), Some("=".to_owned()));
+    assert_eq!(text(lexer.peek_nth(4)), Some("".to_owned()));
+    assert_eq!(text(lexer.peek_nth(5)), None);
+
+    assert_eq!(text(lexer.peek_nth(0)), text(lexer.current()));
+    assert_eq!(text(lexer.peek_nth(1)), text(lexer.peek()));
+
+    // Peeking ahead doesn't change the order tokens are consumed in
+    lexer.consume();
+    assert_eq!(text(lexer.peek_nth(1)), Some("=".to_owned()));
+
+    let tokens = lexer
+        .tokens_iter()
+        .map(|token| token.unwrap().token().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(tokens, vec!["x", "=", "1", ""]);
+}
+
+#[test]
+fn test_lexer_significant() {
+    let code = "-- comment\nlocal  x = --[[ inline ]] 1\n";
+    let tokens = Lexer::new(code, LuaVersion::new())
+        .tokens_iter()
+        .significant()
+        .map(|token| token.unwrap())
+        .collect::<Vec<_>>();
+
+    assert!(tokens.iter().all(|token| !token.token_type().is_trivia()));
+    assert_eq!(
+        tokens.iter().map(Token::to_string).collect::<Vec<_>>(),
+        vec!["local", "x", "=", "1", ""]
+    );
+}
+
+#[test]
+fn test_with_quote_type() {
+    let requote = |token: Token, quote_type| {
+        token
+            .with_quote_type(quote_type)
+            .map(|token| token.to_string())
+    };
+
+    assert_eq!(
+        requote(
+            string("a\"b", StringLiteralQuoteType::Single),
+            StringLiteralQuoteType::Double
+        )
+        .as_deref(),
+        Some(r#""a\"b""#)
+    );
+
+    assert_eq!(
+        requote(
+            string(r#"it's \"quoted\"\n"#, StringLiteralQuoteType::Double),
+            StringLiteralQuoteType::Single
+        )
+        .as_deref(),
+        Some(r#"'it\'s \"quoted\"\n'"#)
+    );
+
+    assert_eq!(
+        requote(
+            string(r"C:\path", StringLiteralQuoteType::Brackets),
+            StringLiteralQuoteType::Double
+        )
+        .as_deref(),
+        Some(r#""C:\\path""#)
+    );
+
+    assert_eq!(
+        requote(
+            string("a\nb", StringLiteralQuoteType::Brackets),
+            StringLiteralQuoteType::Double
+        ),
+        None
+    );
+
+    assert_eq!(
+        requote(
+            string(r"a]]\tb", StringLiteralQuoteType::Double),
+            StringLiteralQuoteType::Brackets
+        )
+        .as_deref(),
+        Some("[=[a]]\tb]=]")
+    );
+
+    assert_eq!(
+        requote(
+            string(r"\nb", StringLiteralQuoteType::Double),
+            StringLiteralQuoteType::Brackets
+        ),
+        None
+    );
+
+    assert_eq!(requote(number("1"), StringLiteralQuoteType::Double), None);
+}
+
+#[test]
+fn test_tokens_include_trivia_and_eof() {
+    let kinds: Vec<_> = tokens("local x -- comment")
+        .unwrap()
+        .iter()
+        .map(Token::token_kind)
+        .collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Symbol,
+            TokenKind::Whitespace,
+            TokenKind::Identifier,
+            TokenKind::Whitespace,
+            TokenKind::SingleLineComment,
+            TokenKind::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_lexer_process_next_after_peek_nth() {
+    let mut lexer = Lexer::new("local x = 1", LuaVersion::new());
+    assert!(lexer.peek_nth(3).is_some());
+
+    // The tokens `peek_nth` processed are returned before the rest of the source string
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.process_next() {
+        tokens.push(token.unwrap().to_string());
+    }
+
+    assert_eq!(tokens, vec!["=", " ", "1", ""]);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "luau")]
+use full_moon::{
+    ast::{self, types::TypeInfo},
+    parse,
+};
+
+fn local_types(code: &str) -> Vec<TypeInfo> {
+    let ast = parse(code).unwrap();
+    let Some(ast::Stmt::LocalAssignment(local_assignment)) = ast.nodes().stmts().next() else {
+        panic!("expected a local assignment");
+    };
+
+    local_assignment
+        .type_specifiers()
+        .map(|type_specifier| {
+            type_specifier
+                .expect("expected a type specifier")
+                .type_info()
+                .clone()
+        })
+        .collect()
+}
+
+#[test]
+fn test_type_info_helpers() {
+    let types = local_types("local a: string, b: Foo?, c: Array<string>, d: nil, e: M.Foo");
+
+    assert!(!types[0].is_optional());
+    assert!(types[0].unwrap_optional().is_none());
+    assert_eq!(types[0].as_simple_name(), Some("string"));
+
+    assert!(types[1].is_optional());
+    assert_eq!(types[1].as_simple_name(), None);
+    assert_eq!(
+        types[1]
+            .unwrap_optional()
+            .and_then(TypeInfo::as_simple_name),
+        Some("Foo")
+    );
+
+    for type_info in &types[2..] {
+        assert!(!type_info.is_optional());
+        assert_eq!(type_info.as_simple_name(), None);
+    }
+}
+
+#[test]
+fn test_typed_bindings() {
+    let ast = parse("local x: number, y = 1, 2").unwrap();
+    let Some(ast::Stmt::LocalAssignment(local_assignment)) = ast.nodes().stmts().next() else {
+        panic!("expected a local assignment");
+    };
+
+    let bindings = local_assignment
+        .typed_bindings()
+        .map(|(name, type_info)| {
+            (
+                name.token().to_string(),
+                type_info.map(|type_info| type_info.to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        bindings,
+        vec![
+            ("x".to_owned(), Some("number".to_owned())),
+            ("y".to_owned(), None),
+        ]
+    );
+}
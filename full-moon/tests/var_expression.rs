@@ -0,0 +1,84 @@
+use full_moon::{ast, parse, tokenizer::TokenReference};
+
+fn first_var_expression(ast: &ast::Ast) -> &ast::VarExpression {
+    let Some(ast::Stmt::Assignment(assignment)) = ast.nodes().stmts().next() else {
+        panic!("expected an assignment");
+    };
+
+    match assignment.variables().iter().next() {
+        Some(ast::Var::Expression(var_expression)) => var_expression,
+        _ => panic!("expected a var expression"),
+    }
+}
+
+#[test]
+fn test_base_name() {
+    let ast = parse("x.y.z = 1").unwrap();
+    let var_expression = first_var_expression(&ast);
+    assert_eq!(
+        var_expression
+            .base_name()
+            .map(|name| name.token().to_string()),
+        Some("x".to_owned())
+    );
+    assert!(!var_expression.is_simple());
+
+    let ast = parse("(f()).x = 1").unwrap();
+    let var_expression = first_var_expression(&ast);
+    assert!(var_expression.base_name().is_none());
+    assert!(!var_expression.is_simple());
+}
+
+#[test]
+fn test_is_simple() {
+    let var_expression =
+        ast::VarExpression::new(ast::Prefix::Name(TokenReference::identifier("x")));
+    assert!(var_expression.is_simple());
+    assert_eq!(
+        var_expression
+            .base_name()
+            .map(|name| name.token().to_string()),
+        Some("x".to_owned())
+    );
+}
+
+#[test]
+fn test_prefix_root_name() {
+    let root_name = |code: &str| {
+        let ast = parse(code).unwrap();
+        first_var_expression(&ast)
+            .prefix()
+            .root_name()
+            .map(|name| name.token().to_string())
+    };
+
+    assert_eq!(root_name("x.y.z = 1").as_deref(), Some("x"));
+    assert_eq!(root_name("(x).y = 1").as_deref(), Some("x"));
+    assert_eq!(root_name("( x ).y = 1").as_deref(), Some("x"));
+    assert_eq!(root_name("((x)).y = 1"), None);
+    assert_eq!(root_name("(x.y).z = 1"), None);
+    assert_eq!(root_name("(f()).x = 1"), None);
+    assert_eq!(root_name("(\"foo\").x = 1"), None);
+}
+
+#[test]
+fn test_index_as_static_key() {
+    let static_keys = |code: &str| {
+        let ast = parse(code).unwrap();
+        first_var_expression(&ast)
+            .suffixes()
+            .map(|suffix| match suffix {
+                ast::Suffix::Index(index) => index.as_static_key().map(|key| key.into_owned()),
+                _ => panic!("expected an index"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(static_keys("t.x = 1"), static_keys("t[\"x\"] = 1"));
+    assert_eq!(static_keys("t.x = 1"), vec![Some("x".to_owned())]);
+    assert_eq!(
+        static_keys("t['a\\tb'][ ([[c]]) ] = 1"),
+        vec![Some("a\tb".to_owned()), Some("c".to_owned())]
+    );
+    assert_eq!(static_keys("t[i][1][x .. 'y'] = 1"), vec![None, None, None]);
+}
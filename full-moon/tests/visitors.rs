@@ -1,7 +1,7 @@
 use full_moon::{
     ast, parse, print,
     tokenizer::*,
-    visitors::{Visitor, VisitorMut},
+    visitors::{NodeKind, NodePathSegment, PathVisitor, TryVisitor, Visitor, VisitorMut},
 };
 
 #[test]
@@ -76,6 +76,58 @@ fn test_visitor_mut() {
     PositionValidator.visit_ast(&code);
 }
 
+#[test]
+fn test_visit_ast_update_positions() {
+    struct Renamer;
+
+    impl VisitorMut for Renamer {
+        fn visit_identifier(&mut self, token: Token) -> Token {
+            match token.token_type() {
+                TokenType::Identifier { identifier } if identifier.as_str() == "x" => {
+                    Token::new(TokenType::Identifier {
+                        identifier: "renamed".into(),
+                    })
+                }
+
+                _ => token,
+            }
+        }
+    }
+
+    let code = parse("local x = 1\nprint(x)").unwrap();
+    let code = Renamer.visit_ast_update_positions(code);
+    assert_eq!(print(&code), "local renamed = 1\nprint(renamed)");
+
+    let tokens = code.tokens().collect::<Vec<_>>();
+    let positions = tokens
+        .iter()
+        .map(|token| {
+            let start = token.token().start_position();
+            (
+                token.token().to_string(),
+                start.bytes(),
+                start.line(),
+                start.character(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        positions,
+        vec![
+            ("local".to_owned(), 0, 1, 1),
+            ("renamed".to_owned(), 6, 1, 7),
+            ("=".to_owned(), 14, 1, 15),
+            ("1".to_owned(), 16, 1, 17),
+            ("print".to_owned(), 18, 2, 1),
+            ("(".to_owned(), 23, 2, 6),
+            ("renamed".to_owned(), 24, 2, 7),
+            (")".to_owned(), 31, 2, 14),
+            ("".to_owned(), 32, 2, 15),
+        ]
+    );
+}
+
 #[test]
 fn test_visit_token() {
     #[derive(Default)]
@@ -170,3 +222,135 @@ fn test_unary_visitor_regression() {
     visitor.visit_ast(&parse("local x = #{}").unwrap());
     assert!(visitor.0, "Unary operation was not visited");
 }
+
+#[test]
+fn test_try_visitor() {
+    use std::ops::ControlFlow;
+
+    #[derive(Default)]
+    struct FirstReturnVisitor {
+        visited_calls: usize,
+    }
+
+    impl TryVisitor for FirstReturnVisitor {
+        type Break = String;
+
+        fn visit_function_call(&mut self, _: &ast::FunctionCall) -> ControlFlow<String> {
+            self.visited_calls += 1;
+            ControlFlow::Continue(())
+        }
+
+        fn visit_return(&mut self, node: &ast::Return) -> ControlFlow<String> {
+            ControlFlow::Break(node.returns().to_string())
+        }
+    }
+
+    let code = parse("a() local function f() b() return 1 end c() return 2").unwrap();
+    let mut visitor = FirstReturnVisitor::default();
+
+    assert_eq!(
+        visitor.visit_ast(&code),
+        ControlFlow::Break("1 ".to_string())
+    );
+    // `c()` is never visited
+    assert_eq!(visitor.visited_calls, 2);
+
+    let code = parse("a() b()").unwrap();
+    let mut visitor = FirstReturnVisitor::default();
+
+    assert_eq!(visitor.visit_ast(&code), ControlFlow::Continue(()));
+    assert_eq!(visitor.visited_calls, 2);
+}
+
+#[test]
+fn test_collect() {
+    use full_moon::visitors::collect;
+
+    let code = parse("local x = 1 if x then local y = call(x) end").unwrap();
+
+    let local_assignments = collect::<ast::LocalAssignment>(&code);
+    assert_eq!(local_assignments.len(), 2);
+    assert_eq!(local_assignments[0].names().to_string(), "x ");
+    assert_eq!(local_assignments[1].names().to_string(), "y ");
+
+    assert_eq!(collect::<ast::Stmt>(&code).len(), 3);
+    assert_eq!(collect::<ast::FunctionCall>(&code).len(), 1);
+    assert!(collect::<ast::While>(&code).is_empty());
+}
+
+#[test]
+fn test_count_tokens() {
+    use full_moon::visitors::{count_tokens, TokenCounter};
+
+    let code = parse("-- comment\nlocal x = \"y\" --[[ a ]] --[[ b ]]").unwrap();
+    let counts = count_tokens(&code);
+
+    assert_eq!(counts[&TokenKind::SingleLineComment], 1);
+    assert_eq!(counts[&TokenKind::MultiLineComment], 2);
+    assert_eq!(counts[&TokenKind::Identifier], 1);
+    assert_eq!(counts[&TokenKind::StringLiteral], 1);
+    assert_eq!(counts[&TokenKind::Symbol], 2);
+    assert!(!counts.contains_key(&TokenKind::Number));
+
+    let mut counter = TokenCounter::new();
+    counter.visit_ast(&code);
+    assert_eq!(counter.counts(), &counts);
+    assert_eq!(counter.count(TokenKind::Number), 0);
+    assert_eq!(
+        counter.count(TokenKind::Whitespace),
+        code.tokens()
+            .flat_map(|token| token.leading_trivia().chain(token.trailing_trivia()))
+            .filter(|token| token.token_kind() == TokenKind::Whitespace)
+            .count()
+    );
+}
+
+#[test]
+fn test_path_visitor() {
+    #[derive(Default)]
+    struct LocalAssignmentPathVisitor {
+        paths: Vec<Vec<NodeKind>>,
+    }
+
+    impl PathVisitor for LocalAssignmentPathVisitor {
+        fn visit_local_assignment(
+            &mut self,
+            _: &ast::LocalAssignment,
+            path: &[NodePathSegment<NodeKind>],
+        ) {
+            self.paths
+                .push(path.iter().map(NodePathSegment::kind).collect());
+        }
+    }
+
+    let code = r#"
+    local function f()
+        if x then
+            do
+                local y = 1
+            end
+        end
+    end
+    "#;
+
+    let mut visitor = LocalAssignmentPathVisitor::default();
+    visitor.visit_ast(&parse(code).unwrap());
+
+    assert_eq!(
+        visitor.paths,
+        vec![vec![
+            NodeKind::VisitBlock,
+            NodeKind::VisitStmt,
+            NodeKind::VisitLocalFunction,
+            NodeKind::VisitFunctionBody,
+            NodeKind::VisitBlock,
+            NodeKind::VisitStmt,
+            NodeKind::VisitIf,
+            NodeKind::VisitBlock,
+            NodeKind::VisitStmt,
+            NodeKind::VisitDo,
+            NodeKind::VisitBlock,
+            NodeKind::VisitStmt,
+        ]]
+    );
+}